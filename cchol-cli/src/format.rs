@@ -0,0 +1,163 @@
+//! Output formats for generated characters.
+use cchol_lib::{pc::PlayerCharacter, social::birth_legitimacy::SiblingLegit};
+use clap::ValueEnum;
+use rpgassist::{ext::IsNamed, gender::HasGender, ranking::rank::IsRanked};
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub(crate) enum Format {
+    Json,
+    Yaml,
+    Markdown,
+    Text,
+    /// Background story in prose.
+    Prose,
+}
+
+/// Whose eyes the output is for.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum View {
+    /// Without GM-only secrets.
+    Player,
+    /// Everything.
+    Gm,
+}
+
+impl Format {
+    /// Render `pcs` — a lone character as such, several as a list — for the given `view`.
+    pub(crate) fn render(&self, pcs: &[PlayerCharacter], view: View) -> Result<String, Box<dyn std::error::Error>> {
+        let player_pcs: Vec<PlayerCharacter>;
+        let pcs = match view {
+            View::Gm => pcs,
+            View::Player => {
+                player_pcs = pcs.iter().map(|pc| pc.player_view()).collect();
+                &player_pcs
+            }
+        };
+        Ok(match (self, pcs) {
+            (Self::Json, [pc]) => serde_jsonc::to_string_pretty(pc)? + "\n",
+            (Self::Json, _) => serde_jsonc::to_string_pretty(pcs)? + "\n",
+            (Self::Yaml, [pc]) => serde_yaml::to_string(pc)?,
+            (Self::Yaml, _) => serde_yaml::to_string(pcs)?,
+            (Self::Markdown, _) => pcs.iter().map(markdown).collect::<Vec<_>>().join("\n"),
+            (Self::Text, _) => pcs.iter().map(text).collect::<Vec<_>>().join("\n"),
+            (Self::Prose, _) => pcs.iter().map(|pc| pc.biography().to_string()).collect::<Vec<_>>().join("\n"),
+        })
+    }
+}
+
+/// Turn e.g. `MotherAndFather { … }` into "Mother and father".
+fn humanize(debug: &str) -> String {
+    let variant = debug.split([' ', '(', '{']).next().unwrap_or_default();
+    let mut out = String::new();
+    for (i, c) in variant.chars().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            out.push(' ');
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Label/value pairs of the essentials.
+fn facts(pc: &PlayerCharacter) -> Vec<(&'static str, String)> {
+    let birth = pc.birth();
+    let bio = pc.biography();
+    let status = pc.social_status();
+    let mut facts = vec![
+        ("Gender", format!("{:?}", pc.gender())),
+        ("Race", pc.race().name().into()),
+        ("Culture", pc.culture().name().into()),
+        ("Social status", status.wealth().name().into()),
+    ];
+    if let Some(noble) = status.nobility() {
+        let mut title = noble.title(pc.gender()).to_string();
+        if !noble.land_titles().is_empty() {
+            title += &format!(" ({})", noble.land_titles().join(", "));
+        }
+        facts.push(("Nobility", title));
+    }
+    facts.push(("Legitimacy", match birth.illegitimacy_reason() {
+        None => "legitimate".into(),
+        Some(reason) => format!("illegitimate — {}", humanize(&format!("{reason:?}")).to_lowercase()),
+    }));
+    facts.push(("Family", humanize(birth.family().as_ref())));
+    let (legit, illegit) = birth.siblings().iter().fold((0, 0), |(l, i), s| match s {
+        SiblingLegit::Legit(_) => (l + 1, i),
+        SiblingLegit::Illegit(_) => (l, i + 1),
+    });
+    facts.push(("Siblings", match illegit {
+        0 => legit.to_string(),
+        _ => format!("{legit} (+{illegit} illegitimate)")
+    }));
+    facts.push(("Birth order", humanize(&format!("{:?}", birth.birth_order()))));
+    facts.push(("Place of birth", birth.place_of_birth().to_string()));
+    if !birth.unusual_birth_circumstances().is_empty() {
+        facts.push(("Unusual birth circumstances", birth.unusual_birth_circumstances().iter()
+            .map(|u| bio.unusual_birth_circumstance(u))
+            .collect::<Vec<_>>()
+            .join(" ")));
+    }
+    if !birth.gm_unusual_birth_circumstances().is_empty() {
+        facts.push(("GM-only birth circumstances", birth.gm_unusual_birth_circumstances().iter()
+            .map(|u| bio.unusual_birth_circumstance(u))
+            .collect::<Vec<_>>()
+            .join(" ")));
+    }
+    let occupations = pc.occupations();
+    if !occupations.parents().is_empty() {
+        facts.push(("Parents' occupation", occupations.parents().iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<_>>()
+            .join("; ")));
+    }
+    if let Some(own) = occupations.own() {
+        facts.push(("Occupation", own.to_string()));
+    }
+    if !pc.skills().is_empty() {
+        facts.push(("Skills", pc.skills().iter()
+            .map(|s| format!("{} {}", s.name(), s.rank()))
+            .collect::<Vec<_>>()
+            .join(", ")));
+    }
+    if !pc.possessions().is_empty() {
+        facts.push(("Possessions", pc.possessions().iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join("; ")));
+    }
+    facts.push(("Starting money", format!("{:.0}", pc.starting_money())));
+    if let Some(seed) = pc.seed() {
+        facts.push(("Seed", seed.to_string()));
+    }
+    facts
+}
+
+pub(crate) fn text(pc: &PlayerCharacter) -> String {
+    let mut out = format!("{}\n", pc.name());
+    for (label, value) in facts(pc) {
+        out += &format!("  {label}: {value}\n");
+    }
+    if !pc.roll_log().is_empty() {
+        out += "  Roll log:\n";
+        for roll in pc.roll_log().iter() {
+            out += &format!("    {roll}\n");
+        }
+    }
+    out
+}
+
+fn markdown(pc: &PlayerCharacter) -> String {
+    let mut out = format!("## {}\n\n", pc.name());
+    for (label, value) in facts(pc) {
+        out += &format!("- **{label}:** {value}\n");
+    }
+    if !pc.roll_log().is_empty() {
+        out += "\n### Roll log\n\n";
+        for roll in pc.roll_log().iter() {
+            out += &format!("- `{roll}`\n");
+        }
+    }
+    out
+}
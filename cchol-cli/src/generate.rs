@@ -0,0 +1,61 @@
+//! `generate` — one or more characters in one go.
+use std::{fs, path::PathBuf};
+
+use cchol_lib::{GenerationOptions, try_generate_player_background};
+use clap::Args;
+
+use crate::format::{Format, View};
+
+#[derive(Args, Debug)]
+pub(crate) struct GenerateArgs {
+    /// Character name(s).
+    #[arg(required = true)]
+    names: Vec<String>,
+    #[arg(short, long)]
+    gender: Option<String>,
+    #[arg(short, long)]
+    race: Option<String>,
+    #[arg(short, long)]
+    culture: Option<String>,
+    /// Dice seed; with more than one character, each next one gets `seed + 1`.
+    #[arg(short, long)]
+    seed: Option<u64>,
+    /// Number of characters to generate per name.
+    #[arg(short = 'n', long, default_value_t = 1)]
+    count: usize,
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Player view leaves out GM-only secrets.
+    #[arg(short, long, value_enum, default_value_t = View::Gm)]
+    view: View,
+    /// Write to file instead of stdout.
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+    /// Include every table roll made.
+    #[arg(short = 'l', long)]
+    roll_log: bool,
+}
+
+pub(crate) fn run(args: GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut pcs = vec![];
+    for name in &args.names {
+        for _ in 0..args.count {
+            let options = GenerationOptions {
+                name: name.clone(),
+                gender: args.gender.clone(),
+                race: args.race.clone(),
+                culture: args.culture.clone(),
+                seed: args.seed.map(|s| s.wrapping_add(pcs.len() as u64)),
+                roll_log: args.roll_log,
+            };
+            pcs.push(try_generate_player_background(&options)?);
+        }
+    }
+
+    let output = args.format.render(&pcs, args.view)?;
+    match args.out {
+        Some(file) => fs::write(file, output)?,
+        None => print!("{output}")
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! `interactive` — one character, step by step.
+use std::{io::{self, BufRead, Write}, path::PathBuf};
+
+use cchol_lib::{GenerationOptions, save::save_to, stepwise::{Step, StepResult, Stepwise}};
+use clap::Args;
+
+use crate::format::text;
+
+#[derive(Args, Debug)]
+pub(crate) struct InteractiveArgs {
+    /// Character name.
+    name: String,
+    #[arg(short, long)]
+    gender: Option<String>,
+    #[arg(short, long)]
+    seed: Option<u64>,
+    /// Where to save the character; defaults to `<name>.json`.
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+    /// Include every table roll made.
+    #[arg(short = 'l', long)]
+    roll_log: bool,
+}
+
+pub(crate) fn run(args: InteractiveArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let options = GenerationOptions {
+        name: args.name.clone(),
+        gender: args.gender,
+        race: None,
+        culture: None,
+        seed: args.seed,
+        roll_log: args.roll_log,
+    };
+    let mut stepwise = Stepwise::new(&options)?;
+    let mut input = io::stdin().lock();
+
+    while stepwise.step() != Step::Done {
+        let mut result = stepwise.roll();
+        loop {
+            show(stepwise.step(), &result);
+            let choices = stepwise.choices();
+            let prompt = if choices.is_empty() { "[a]ccept, [r]eroll" } else { "[a]ccept, [r]eroll, [p]ick" };
+            match ask(&mut input, prompt)?.as_str() {
+                "" | "a" => break,
+                "r" => result = stepwise.roll(),
+                "p" if !choices.is_empty() => {
+                    for (i, choice) in choices.iter().enumerate() {
+                        println!("{:>4}. {choice}", i + 1);
+                    }
+                    let picked = ask(&mut input, "number")?;
+                    match picked.parse::<usize>().ok().and_then(|i| i.checked_sub(1)).map(|i| stepwise.pick(i)) {
+                        Some(Ok(picked)) => result = picked,
+                        _ => println!("No such choice: {picked}"),
+                    }
+                },
+                other => println!("Eh? {other}"),
+            }
+        }
+        stepwise.accept()?;
+    }
+
+    let pc = stepwise.finish()?;
+    println!("\n{}", text(&pc));
+    let out = args.out.unwrap_or_else(|| PathBuf::from(format!("{}.json", args.name)));
+    save_to(&out, &pc)?;
+    println!("Saved to {}", out.display());
+    Ok(())
+}
+
+/// Show a step's rolls and result.
+fn show(step: Step, result: &StepResult) {
+    println!("\n== {step} ==");
+    for roll in result.rolls() {
+        println!("  {roll}");
+    }
+    println!("→ {}", result.result());
+}
+
+/// Ask for a line of input; EOF counts as "accept".
+fn ask(input: &mut impl BufRead, prompt: &str) -> io::Result<String> {
+    print!("{prompt}: ");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    Ok(line.trim().to_lowercase())
+}
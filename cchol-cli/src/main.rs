@@ -1,6 +1,12 @@
-use cchol_lib::{pc::PlayerCharacter, racial::Race, social::culture::Culture};
-use clap::Parser;
-use rpgassist::{gender::Gender, ext::IsNamed};
+use std::path::PathBuf;
+
+use cchol_lib::data::{DataSource, set_data_packs, set_data_source};
+use clap::{Parser, Subcommand};
+
+mod format;
+mod generate;
+mod interactive;
+mod show;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -9,21 +15,46 @@ use rpgassist::{gender::Gender, ext::IsNamed};
     about = "CCHoL-CLI © 2025 Markku Sukanen.\nMIT / Apache-2.0 license applies.\n\nA simple CLI for fantasy/medieval RPG character background generation.")
 ]
 struct Cli {
-    name: String,
-    #[arg(short, long)]
-    gender: Option<String>,
-    #[arg(short, long)]
-    race: Option<String>,
-    #[arg(short, long)]
-    culture: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+    /// Load data from given directory instead of the built-in data set.
+    #[arg(short, long, global = true)]
+    data_dir: Option<PathBuf>,
+    /// Layer a homebrew data pack over the data; repeatable, later packs win.
+    #[arg(short, long = "pack", global = true)]
+    packs: Vec<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generate character background(s) in one go.
+    Generate(generate::GenerateArgs),
+    /// Generate a character step by step: accept, reroll or hand-pick each result.
+    Interactive(interactive::InteractiveArgs),
+    /// Show saved character(s).
+    Show(show::ShowArgs),
 }
 
 /// Simple CLI thingy.
 fn main() {
     let _ = env_logger::try_init();
     let args = Cli::parse();
-    let pc = PlayerCharacter::random(&args.name);
+    if let Err(e) = run(args) {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
 
-    println!("Generating stuff for {pc:?}");
-    //let p = PlayerCharacter::new("MJS", Some(Gender::Male), None);
- }
\ No newline at end of file
+fn run(args: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = args.data_dir {
+        set_data_source(DataSource::Dir(dir))?;
+    }
+    if !args.packs.is_empty() {
+        set_data_packs(args.packs)?;
+    }
+    match args.command {
+        Command::Generate(args) => generate::run(args),
+        Command::Interactive(args) => interactive::run(args),
+        Command::Show(args) => show::run(args),
+    }
+}
@@ -0,0 +1,27 @@
+//! `show` — saved character(s).
+use std::path::PathBuf;
+
+use cchol_lib::save::load_from;
+use clap::Args;
+
+use crate::format::{Format, View};
+
+#[derive(Args, Debug)]
+pub(crate) struct ShowArgs {
+    /// Save file(s) to show.
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Player view leaves out GM-only secrets.
+    #[arg(short, long, value_enum, default_value_t = View::Gm)]
+    view: View,
+}
+
+pub(crate) fn run(args: ShowArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let pcs = args.files.iter()
+        .map(load_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    print!("{}", args.format.render(&pcs, args.view)?);
+    Ok(())
+}
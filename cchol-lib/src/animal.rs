@@ -1,12 +1,12 @@
-use std::fs;
-
 use bitflags::bitflags;
 use cchol_pm::{Gendered, HasName};
-use dicebag::{DiceExt, IsOne, RandomOf};
+use dicebag::IsOne;
 use lazy_static::lazy_static;
 use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}, resolve::resolve_in_place::ResolveInPlace, serialize::serial_strings::deserialize_strings_to_vec};
 use serde::{Deserialize, Deserializer, Serialize, de};
 
+use crate::{data::{check, load_json, loaded}, dice::{DiceExt, RandomOf}, error::Result};
+
 pub mod pet;
 use pet::PetAbility;
 
@@ -108,9 +108,9 @@ pub struct AnimalCore {
     }
 }
 
-static ANIMAL_FILE: &'static str = "./data/animal.json";
+static ANIMAL_FILE: &'static str = "animal.json";
 lazy_static! {
-    static ref ANIMALS: Vec<AnimalCore> = {
+    static ref ANIMALS_DATA: Result<Vec<AnimalCore>> = {
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum AnimaEntry {
@@ -133,10 +133,16 @@ lazy_static! {
             }
         }
 
-        let json = &fs::read_to_string(ANIMAL_FILE).expect(format!("Error with '{ANIMAL_FILE}'?!").as_str());
-        let items: Vec<AnimaEntry> = serde_jsonc::from_str(json).expect("JSON error");
-        items.into_iter().map(anima_shaper).collect()
+        load_json::<Vec<AnimaEntry>>(ANIMAL_FILE)
+            .map(|items| items.into_iter().map(anima_shaper).collect())
     };
+    static ref ANIMALS: &'static Vec<AnimalCore> = loaded(&ANIMALS_DATA);
+}
+
+/// Load [AnimalCore] data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&ANIMALS_DATA)?;
+    Ok(())
 }
 
 impl Animal {
@@ -186,9 +192,8 @@ impl ResolveInPlace for AnimalCore {
 #[cfg(test)]
 // Don't call PETA even though the mod is called "animal tests"!!!
 mod animal_tests {
-    use dicebag::RandomOf;
 
-    use crate::animal::ANIMALS;
+    use crate::{animal::ANIMALS, dice::RandomOf};
 
     #[test]
     fn animal_file_data_integrity() {
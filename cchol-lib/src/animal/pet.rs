@@ -1,10 +1,9 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 
-use dicebag::{DiceExt, lo, HiLo};
 use rpgassist::{stat::Stat, serialize::serial_uf64::deserialize as uf64deserialize};
 use serde::{Deserialize, Serialize};
 
-use crate::misc::{ExoticColor, Substance};
+use crate::{body::PhysicalAffliction, dice::{DiceExt, lo}, misc::{ExoticColor, Substance}};
 
 /// PetAbility enum variant count.
 ///
@@ -18,9 +17,9 @@ pub enum PetAbility {
     HighIQ { stat: Stat, can_speak: bool },
     Telepathic,
     UnusualColor { colors: Vec<ExoticColor> },
-    UnusualSubstance { materials: HashSet<Substance> },
+    UnusualSubstance { materials: BTreeSet<Substance> },
     //-- 5
-    //TODO T874 - PhysicalAffliction(PhysicalAffliction)
+    PhysicalAffliction(PhysicalAffliction),
     CanUseMagic,
     InvisibleToAllButOwner,
     Regenerates { speed_factor: u8 },
@@ -41,7 +40,7 @@ pub enum PetAbility {
     CanDiscorporateIntoMist,
 } impl PetAbility {
     pub fn random() -> Vec<Self> {
-        let mut pab_collection: HashMap<u8, u8> = HashMap::new();
+        let mut pab_collection: BTreeMap<u8, u8> = BTreeMap::new();
         let mut i = 1;
         // Generate at most 4 distinct IDs.
         while i > 0 && pab_collection.len() < 4 {
@@ -72,14 +71,17 @@ pub enum PetAbility {
                     cs
                 }},
                 5 => Self::UnusualSubstance { materials: {
-                    let mut mats = HashSet::new();
+                    let mut mats = BTreeSet::new();
                     // sum up all the material types
                     for _ in 0..*stack {
                         mats.extend(Substance::random())
                     }
                     mats
                 }},
-                6 => unimplemented!("TODO 874"),
+                6 => Self::PhysicalAffliction(match stack {
+                    1 => PhysicalAffliction::roll(),
+                    _ => PhysicalAffliction::Multiple((0..*stack).map(|_| PhysicalAffliction::roll()).collect())
+                }),
                 7 => Self::CanUseMagic,
                 8 => Self::InvisibleToAllButOwner,
                 9 => Self::Regenerates { speed_factor: *stack },
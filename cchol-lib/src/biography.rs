@@ -0,0 +1,418 @@
+//! # Biography
+//!
+//! Turns a finished [PlayerCharacter] into a readable, multi-paragraph
+//! background story — see [PlayerCharacter::biography].
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}};
+
+use crate::{events::{Curse, Ubc3941, Ubc4950, UnusualBirthCircumstance}, misc::OccupationPerformance, pc::PlayerCharacter, social::{birth::BirthOrder, birth_legitimacy::{IllegitimacyReason, SiblingLegit}, family::FamilyStructure, people::guardian::Guardian}, string_manip::{Pronouns, a_or_an}};
+
+/// A [PlayerCharacter]'s background in prose; paragraphs separated by an empty line.
+pub struct Biography<'a> {
+    pc: &'a PlayerCharacter,
+    p: Pronouns,
+}
+
+impl PlayerCharacter {
+    /// Get the character's background as a [Biography], ready for [Display].
+    pub fn biography(&self) -> Biography<'_> {
+        Biography { pc: self, p: Pronouns::from(self.gender()) }
+    }
+}
+
+/// Capitalize the first letter of `text`.
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new()
+    }
+}
+
+/// Lowercase the first letter of `text`, unless it looks like an acronym or such.
+fn decapitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), Some(d)) if !d.is_uppercase() => c.to_lowercase().chain(text.chars().skip(1)).collect(),
+        _ => text.into()
+    }
+}
+
+/// Join `items` as "x, y and z".
+fn and_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", "))
+    }
+}
+
+/// "1 brother", "2 sisters", etc.
+fn count_of(n: usize, one: &str, many: &str) -> String {
+    format!("{n} {}", if n == 1 {one} else {many})
+}
+
+impl Biography<'_> {
+    /// Race, culture, wealth and nobility.
+    fn origins(&self) -> String {
+        let pc = self.pc;
+        let race = pc.race().name();
+        let status = pc.social_status();
+        let mut out = format!("{} is {} {race} of {} culture, born into {} {} family.",
+            pc.name(), a_or_an(race), pc.culture().name().to_lowercase(),
+            a_or_an(status.wealth().name()), status.wealth().name().to_lowercase());
+        if let Some(noble) = status.nobility() {
+            out += &format!(" {} family is of noble blood, and {} {} the title of {}",
+                capitalize(self.p.possessive), self.p.subject, self.p.has(), noble.title(pc.gender()));
+            if !noble.land_titles().is_empty() {
+                out += &format!(", along with that of {}", and_list(noble.land_titles()));
+            }
+            out += ".";
+        }
+        out
+    }
+
+    /// (Il)legitimacy and place of birth.
+    fn birth(&self) -> String {
+        let p = &self.p;
+        let birth = self.pc.birth();
+        let mut out = String::new();
+        if let Some(reason) = birth.illegitimacy_reason() {
+            let father = |known: &bool| if *known {
+                format!("{} father is known", p.possessive)
+            } else {
+                format!("nobody knows who {} father is", p.possessive)
+            };
+            out += &match reason {
+                IllegitimacyReason::MotherCommonPrositute =>
+                    format!("{} {} born out of wedlock to a common prostitute. ", capitalize(p.subject), p.was()),
+                IllegitimacyReason::MotherRaped { father_known } =>
+                    format!("{} {} conceived when {} mother was raped; {}. ", capitalize(p.subject), p.was(), p.possessive, father(father_known)),
+                IllegitimacyReason::MotherUnmarried { father_known } =>
+                    format!("{} {} born out of wedlock to an unmarried mother, and {}. ", capitalize(p.subject), p.was(), father(father_known)),
+                IllegitimacyReason::MotherCourtesan { father_known } =>
+                    format!("{} {} born out of wedlock to a courtesan, and {}. ", capitalize(p.subject), p.was(), father(father_known)),
+            };
+        }
+        let place = birth.place_of_birth().to_string();
+        out += &format!("{} {} born {}.", capitalize(p.subject), p.was(), decapitalize(&place));
+        out
+    }
+
+    /// Who raised the character.
+    fn family(&self, family: &FamilyStructure) -> String {
+        let p = &self.p;
+        let raised_by = |whom: String| format!("{} {} raised by {whom}.", capitalize(p.subject), p.was());
+        let side = |side: &Gender| match side {
+            Gender::Male => format!(" on {} father's side", p.possessive),
+            Gender::Female => format!(" on {} mother's side", p.possessive),
+            _ => String::new()
+        };
+        match family {
+            FamilyStructure::Adopted(family) =>
+                format!("{} {} adopted. {}", capitalize(p.subject), p.was(), self.family(family)),
+            FamilyStructure::MotherAndFather => raised_by(format!("{} mother and father", p.possessive)),
+            FamilyStructure::Extended { grandparents, auncles, cousins } => {
+                let aunts = auncles.iter().filter(|r| r.gender() == Gender::Female).count();
+                let relatives: Vec<String> = [
+                    (grandparents.len(), "grandparent", "grandparents"),
+                    (aunts, "aunt", "aunts"),
+                    (auncles.len() - aunts, "uncle", "uncles"),
+                    (cousins.len(), "cousin", "cousins"),
+                ].into_iter()
+                    .filter(|(n, ..)| *n > 0)
+                    .map(|(n, one, many)| count_of(n, one, many))
+                    .collect();
+                raised_by(format!("{} parents in an extended family, together with {}", p.possessive, and_list(&relatives)))
+            },
+            FamilyStructure::Clan { primary_mother_figure, primary_father_figure, close_members } => {
+                let figures = match (primary_mother_figure, primary_father_figure) {
+                    (true, true) => ", among them both a mother and a father figure",
+                    (true, false) => ", one of them a mother figure",
+                    (false, true) => ", one of them a father figure",
+                    _ => ""
+                };
+                raised_by(format!("{} clan, {close_members} of its members close to {}{figures}", p.possessive, p.object))
+            },
+            FamilyStructure::Grandparents { side: s } => raised_by(format!("{} grandparents{}", p.possessive, side(s))),
+            FamilyStructure::Grandparent { specs } |
+            FamilyStructure::AuntOrUncle { specs } => raised_by(format!("{} {specs}", p.possessive)),
+            FamilyStructure::SingleParent { gender } => raised_by(match gender {
+                Gender::Male => format!("{} father alone", p.possessive),
+                Gender::Female => format!("{} mother alone", p.possessive),
+                _ => "a single parent".into()
+            }),
+            FamilyStructure::AuntAndUncle { side: s } => raised_by(format!("an aunt and uncle{}", side(s))),
+            FamilyStructure::Orphanage => format!("{} grew up in an orphanage.", capitalize(p.subject)),
+            FamilyStructure::StreetKid { .. } => format!("{} grew up on the streets, fending for {}.", capitalize(p.subject), p.reflexive),
+            FamilyStructure::Guardian(guardian) => match guardian {
+                Guardian::Relative(relation) => raised_by(format!("{} {relation}", p.possessive)),
+                Guardian::Orphanage => self.family(&FamilyStructure::Orphanage),
+                Guardian::Adopted(family) => self.family(&FamilyStructure::Adopted(family.clone())),
+                Guardian::Institution(institution) => raised_by(institution.to_string()),
+                Guardian::Unusual(guardian) => raised_by(guardian.to_string()),
+            }
+        }
+    }
+
+    /// Siblings and birth order.
+    fn siblings(&self) -> String {
+        let p = &self.p;
+        let birth = self.pc.birth();
+        let siblings = birth.siblings();
+        if siblings.is_empty() {
+            return format!("{} {} an only child.", capitalize(p.subject), p.is());
+        }
+        let brothers = siblings.iter().filter(|s| s.gender() == Gender::Male).count();
+        let sisters = siblings.len() - brothers;
+        let illegit = siblings.iter().filter(|s| matches!(s, SiblingLegit::Illegit(_))).count();
+        let mut counts = vec![];
+        if brothers > 0 { counts.push(count_of(brothers, "brother", "brothers")); }
+        if sisters > 0 { counts.push(count_of(sisters, "sister", "sisters")); }
+        let mut out = format!("{} {} {}", capitalize(p.subject), p.has(), and_list(&counts));
+        if illegit > 0 {
+            out += &format!(" ({} of them illegitimate)", illegit);
+        }
+        out += &format!(", and {} the {}.", p.is(), match birth.birth_order() {
+            BirthOrder::First => "firstborn",
+            BirthOrder::Second => "second-born",
+            BirthOrder::LastOf3 => "youngest of three",
+            BirthOrder::Middle => "middle child",
+            BirthOrder::LastOf4 => "youngest of four",
+            BirthOrder::SecondToLast => "second youngest",
+            BirthOrder::Last => "youngest",
+        });
+        out
+    }
+
+    /// Who in the household does what for a living, and what trade the character took up.
+    fn occupations(&self) -> Option<String> {
+        let p = &self.p;
+        let occupations = self.pc.occupations();
+        let a = |o: &OccupationPerformance| format!("{} {}", a_or_an(o.occupation().name()), o.occupation());
+        let mut out = match occupations.parents() {
+            [] => vec![],
+            [head] => vec![format!("The head of {} household works as {}.", p.possessive, a(head))],
+            [head, other, ..] => vec![format!("The head of {} household works as {}, and the other parent as {}.", p.possessive, a(head), a(other))],
+        };
+        if let Some(own) = occupations.own() {
+            let level = own.achievement().to_string().to_lowercase();
+            out.push(match occupations.parents().first() {
+                Some(head) if head.occupation().name() == own.occupation().name() =>
+                    format!("{} {} taken up the same trade, as {} {level}.", capitalize(p.subject), p.has(), a_or_an(&level)),
+                _ => format!("{} {} learned to be {}, as {} {level}.", capitalize(p.subject), p.has(), a(own), a_or_an(&level)),
+            });
+        }
+        if out.is_empty() {None} else {Some(out.join(" "))}
+    }
+
+    /// Describe a [Curse], who cast it, why, and how to get rid of it.
+    fn curse(&self, curse: &Curse) -> String {
+        format!("a {} curse of {}, cast by {} {}. It can be lifted {}",
+            curse.severity(), curse.name(), curse.caster(), curse.reason(), curse.lifted_by())
+    }
+
+    /// A clause about one of the strange happenings of a [Ubc3941] kind.
+    fn happening(&self, happening: &Ubc3941) -> String {
+        let p = &self.p;
+        match happening {
+            Ubc3941::E01 { .. } => format!("the air crackled with raw magic, and some of it lingered in {}", p.object),
+            Ubc3941::E0203 => "all the milk for miles around curdled".into(),
+            Ubc3941::E0405 => "every candle and hearth fire nearby guttered out at once".into(),
+            Ubc3941::E06 { .. } => format!("spells cast nearby fizzled, and {} own spark of magic was dimmed", p.possessive),
+            Ubc3941::E07 => "a two-headed calf was born the same night".into(),
+            Ubc3941::E0809 => "a comet burned across the sky".into(),
+            Ubc3941::E10 => "the dead were seen walking in the graveyard".into(),
+        }
+    }
+
+    /// A sentence (or few) about an [UnusualBirthCircumstance] of the character's.
+    pub fn unusual_birth_circumstance(&self, ubc: &UnusualBirthCircumstance) -> String {
+        let p = &self.p;
+        let subject = capitalize(p.subject);
+        let born = format!("{subject} {} born", p.was());
+        let happenings = |happenings: &BTreeSet<Ubc3941>| and_list(&happenings.iter().map(|h| self.happening(h)).collect::<Vec<_>>());
+        match ubc {
+            UnusualBirthCircumstance::UbcNe05 => format!("Nothing much out of the ordinary marked {} birth, whatever the gossips say.", p.possessive),
+            UnusualBirthCircumstance::Ubc0610 => format!("{born} on a holy day."),
+            UnusualBirthCircumstance::Ubc1120 => format!("Dogs and wolves howled all through the night {} {} born.", p.subject, p.was()),
+            UnusualBirthCircumstance::Ubc2123 => format!("{born} during a total eclipse."),
+            UnusualBirthCircumstance::Ubc2425 => format!("{born} in the midst of a raging storm."),
+            UnusualBirthCircumstance::Ubc2627 => format!("{subject} came into the world weeks before anyone expected."),
+            UnusualBirthCircumstance::Ubc2831 { separated_at_birth, drastically_diff_persona } => {
+                let mut out = format!("{subject} {} one of twins", p.is());
+                if *separated_at_birth {
+                    out += ", separated at birth";
+                }
+                if *drastically_diff_persona {
+                    out += &format!("; {} twin's personality is drastically different from {} own", p.possessive, p.possessive);
+                }
+                out + "."
+            },
+            UnusualBirthCircumstance::Ubc3234 => format!("A person of note passed away in the very hour {} {} born.", p.subject, p.was()),
+            UnusualBirthCircumstance::Ubc3537 => format!("{born} with the cord wrapped around {} neck, and barely survived.", p.possessive),
+            UnusualBirthCircumstance::Ubc38nn => format!("{born} with a full set of teeth."),
+            UnusualBirthCircumstance::Ubc3941(h) => format!("Strange things happened when {} {} born: {}.", p.subject, p.was(), happenings(h)),
+            UnusualBirthCircumstance::Ubc4244(h) => format!("Eerie omens marked {} birth, and folk still whisper of them: {}.", p.possessive, happenings(h)),
+            UnusualBirthCircumstance::Ubc4548 { curse } |
+            UnusualBirthCircumstance::Ubc7075 { curse } => format!("{born} under {}.", self.curse(curse)),
+            UnusualBirthCircumstance::Ubc4950(how) => match how {
+                Ubc4950::E01 => format!("{born} feet first."),
+                Ubc4950::E02 => format!("{born} with a caul over {} face.", p.possessive),
+                Ubc4950::E03 => format!("{born} with {} eyes wide open, and never once cried.", p.possessive),
+            },
+            UnusualBirthCircumstance::Ubc5153 => format!("{born} amid a great celebration, and the revelers took {} arrival for a good omen.", p.possessive),
+            UnusualBirthCircumstance::Ubc5455 => format!("{born} on the very same day as the heir to the throne."),
+            UnusualBirthCircumstance::Ubc56nn => format!("A stranger came to the door the night {} {} born, looked upon the child, and left without a word.", p.subject, p.was()),
+            UnusualBirthCircumstance::Ubc57nn => format!("A white raven perched on the roof all through {} birth.", p.possessive),
+            UnusualBirthCircumstance::Ubc5862 => format!("{born} in the depths of a winter so bitter that the old folk still speak of it."),
+            UnusualBirthCircumstance::Ubc6364 { tragedy } => format!("Tragedy struck at {} birth: {tragedy}.", p.possessive),
+            UnusualBirthCircumstance::Ubc6569 { birthmark } => format!("{subject} {} {} {birthmark}.", p.has(), a_or_an(&birthmark.to_string())),
+            UnusualBirthCircumstance::Ubc7681 { blessing } => format!("{born} with the {blessing}."),
+            UnusualBirthCircumstance::Ubc8285 { gender } => {
+                let child = |gender: &Gender| match gender {
+                    Gender::Male => "boy",
+                    Gender::Female => "girl",
+                    _ => "child"
+                };
+                let mut out = format!("A soothsayer foretold a {} long before {} {} born", child(gender), p.subject, p.was());
+                out += if *gender == self.pc.gender() {", and so it came to be."} else {", and was proven wrong."};
+                out
+            },
+            UnusualBirthCircumstance::Ubc86nn => format!("{born} at the stroke of midnight."),
+            UnusualBirthCircumstance::Ubc8788 { prophesy } => match prophesy.player_view() {
+                Some(death) => format!("It has been foretold that {} will die {death}.", p.subject),
+                None => format!("The manner of {} death has been foretold, though what the prophecy says remains a secret.", p.possessive),
+            },
+            UnusualBirthCircumstance::Ubc8993 { affliction } => format!("{born} {affliction}."),
+            UnusualBirthCircumstance::Ubc94nn { psi } => format!("{born} with psionic powers: {psi}."),
+            UnusualBirthCircumstance::Ubc9599 { gift } => format!("{born} with a special gift: {gift}."),
+            UnusualBirthCircumstance::Ubc100 { ubc1, ubc2 } => format!("{} {}", self.unusual_birth_circumstance(ubc1), self.unusual_birth_circumstance(ubc2)),
+            UnusualBirthCircumstance::Ubc101105 => format!("{born} in the very hour a great hero died, and some say the hero's soul lives on in {}.", p.object),
+            UnusualBirthCircumstance::Ubc106110 { affliction, curse, dln, .. } =>
+                format!("{born} {affliction}, and under {}. A darker side shows in {} already: {}.", self.curse(curse), p.object, dln.name().to_lowercase()),
+            UnusualBirthCircumstance::Ubc111xxx { deity, blessing, affliction, gift, .. } =>
+                format!("{born} touched by the {}: gifted with {gift}, and with the {blessing}, if also {affliction}.", deity.name()),
+        }
+    }
+}
+
+impl Display for Biography<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.origins())?;
+        writeln!(f)?;
+        writeln!(f, "{}", self.birth())?;
+        writeln!(f)?;
+        write!(f, "{} {}", self.family(self.pc.birth().family()), self.siblings())?;
+        match self.occupations() {
+            Some(occupations) => writeln!(f, " {occupations}")?,
+            None => writeln!(f)?
+        }
+        let ubcs = self.pc.birth().unusual_birth_circumstances();
+        if !ubcs.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{}", ubcs.iter().map(|u| self.unusual_birth_circumstance(u)).collect::<Vec<_>>().join(" "))?;
+        }
+        let gm_ubcs = self.pc.birth().gm_unusual_birth_circumstances();
+        if !gm_ubcs.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "For the GM's eyes only: {}", gm_ubcs.iter().map(|u| self.unusual_birth_circumstance(u)).collect::<Vec<_>>().join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod biography_tests {
+    use rpgassist::stat::Stat;
+
+    use crate::{GenerationOptions, body::Birthmark, generate_player_background, social::people::Relation};
+
+    use super::*;
+
+    #[test]
+    fn reads_like_prose() {
+        let mut options = GenerationOptions::new("Tester");
+        options.with_seed(42).with_gender("female");
+        let bio = generate_player_background(&options).biography().to_string();
+        assert!(bio.starts_with("Tester is a"));
+        assert!(bio.contains("\n\nShe was born "));
+        assert!(!bio.contains(" he "));
+        assert!(!bio.contains('{'));
+    }
+
+    #[test]
+    fn relations() {
+        assert_eq!("great-granddaughter", Relation::Descendant { generations_removed: 3, gender: Gender::Female }.to_string());
+    }
+
+    #[test]
+    fn every_circumstance_reads_like_prose() {
+        let mut options = GenerationOptions::new("Tester");
+        options.with_seed(112).with_gender("male");
+        let pc = generate_player_background(&options);
+        let bio = pc.biography();
+        let everything: BTreeSet<Ubc3941> = [
+            Ubc3941::E01 { stat: Stat::Mag { val: 1 } }, Ubc3941::E0203, Ubc3941::E0405,
+            Ubc3941::E06 { stat: Stat::Mag { val: -1 } }, Ubc3941::E07, Ubc3941::E0809, Ubc3941::E10
+        ].into();
+        let ubcs = [
+            UnusualBirthCircumstance::UbcNe05, UnusualBirthCircumstance::Ubc0610, UnusualBirthCircumstance::Ubc1120,
+            UnusualBirthCircumstance::Ubc2123, UnusualBirthCircumstance::Ubc2425, UnusualBirthCircumstance::Ubc2627,
+            UnusualBirthCircumstance::Ubc3234, UnusualBirthCircumstance::Ubc3537, UnusualBirthCircumstance::Ubc38nn,
+            UnusualBirthCircumstance::Ubc3941(everything.clone()), UnusualBirthCircumstance::Ubc4244(everything),
+            UnusualBirthCircumstance::Ubc4950(Ubc4950::E01), UnusualBirthCircumstance::Ubc4950(Ubc4950::E02),
+            UnusualBirthCircumstance::Ubc4950(Ubc4950::E03), UnusualBirthCircumstance::Ubc5153,
+            UnusualBirthCircumstance::Ubc5455, UnusualBirthCircumstance::Ubc56nn, UnusualBirthCircumstance::Ubc57nn,
+            UnusualBirthCircumstance::Ubc5862, UnusualBirthCircumstance::Ubc8285 { gender: Gender::Female },
+            UnusualBirthCircumstance::Ubc86nn, UnusualBirthCircumstance::Ubc101105,
+        ];
+        for ubc in &ubcs {
+            let text = bio.unusual_birth_circumstance(ubc);
+            assert!(text.ends_with('.'), "{text}");
+            assert!(!text.contains("table 112"), "{text}");
+            assert!(!text.contains(" she ") && !text.contains(" her "), "{text}");
+        }
+        assert!(bio.unusual_birth_circumstance(&UnusualBirthCircumstance::Ubc8285 { gender: Gender::Female }).contains("proven wrong"));
+        for _ in 0..100 {
+            let text = bio.unusual_birth_circumstance(&UnusualBirthCircumstance::Ubc6569 { birthmark: Birthmark::random() });
+            let words: Vec<&str> = text.split_whitespace().collect();
+            for w in words.windows(2).filter(|w| w[0] == "a" || w[0] == "an") {
+                assert_eq!(a_or_an(w[1]), w[0], "{text}");
+            }
+        }
+
+        for seed in 0..200 {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed);
+            assert!(!generate_player_background(&options).biography().to_string().contains("table 112"));
+        }
+    }
+
+    #[test]
+    fn place_of_birth_is_never_blank() {
+        for seed in 0..300 {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed);
+            let bio = generate_player_background(&options).biography().to_string();
+            assert!(!bio.contains("born ."), "seed {seed}: {bio}");
+        }
+    }
+
+    #[test]
+    fn gm_secrets_stay_out_of_player_view() {
+        let pc = (0..100).map(|seed| {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed);
+            generate_player_background(&options)
+        }).find(|pc| !pc.birth().gm_unusual_birth_circumstances().is_empty()).unwrap();
+        assert!(pc.biography().to_string().contains("For the GM's eyes only: "));
+
+        let player = pc.player_view();
+        assert!(player.birth().gm_unusual_birth_circumstances().is_empty());
+        assert_eq!(pc.birth().unusual_birth_circumstances().len(), player.birth().unusual_birth_circumstances().len());
+        assert!(!player.biography().to_string().contains("For the GM's eyes only"));
+    }
+}
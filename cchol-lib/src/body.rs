@@ -1,2 +1,4 @@
+mod affliction;
+pub use affliction::PhysicalAffliction;
 mod birthmark;
 pub use birthmark::Birthmark;
\ No newline at end of file
@@ -0,0 +1,193 @@
+//! 874: Physical Afflictions
+use std::fmt::Display;
+
+use rpgassist::{body::location::BodyLocation, direction::bilateral::Bilateral, stat::Stat};
+use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
+
+use crate::{StatMap, Workpad, dice::{DiceExt, lo, random_lr}, roll_log};
+
+/// Physical afflictions — from birth, from a [wound][crate::misc::SeriousWound], or of a pet.
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
+pub enum PhysicalAffliction {
+    /// Lame in one leg.
+    Lame(Bilateral),
+    /// Missing arm, hand, leg, foot, thumb or some fingers.
+    MissingLimb(BodyLocation),
+    Sickly,
+    Albino,
+    /// Blind in one eye, or in both if `None`.
+    Blind(Option<Bilateral>),
+    /// Deaf in one ear, or in both if `None`.
+    Deaf(Option<Bilateral>),
+    Mute,
+    Hunchback,
+    ClubFoot(Bilateral),
+    Frail,
+    WeakHeart,
+    /// Extra finger(s) in one hand.
+    ExtraFingers { count: u8, side: Bilateral },
+    Harelip,
+    PoorEyesight,
+    Palsy,
+    Seizures,
+    Stunted,
+    /// More than one affliction.
+    Multiple(Vec<PhysicalAffliction>),
+} impl PhysicalAffliction {
+    /// Generate a random [PhysicalAffliction] and apply its stat penalties, if any, into `workpad`.
+    pub fn random(workpad: &mut Workpad) -> Self {
+        let affliction = Self::roll();
+        for stat in affliction.stats() {
+            *workpad += stat;
+        }
+        affliction
+    }
+
+    /// Generate a random [PhysicalAffliction] without applying it anywhere, e.g. for pets.
+    ///
+    /// See [apply_to][Self::apply_to] for applying the stat penalties afterwards.
+    pub fn roll() -> Self {
+        let roll = 1.d20();
+        let affliction = match roll {
+            ..=2 => Self::Lame(random_lr()),
+            3 => Self::MissingLimb(match 1.d5() {
+                ..=1 => BodyLocation::Arm(random_lr()),
+                2 => BodyLocation::Hand(random_lr()),
+                3 => BodyLocation::Leg(random_lr()),
+                4 => BodyLocation::Foot(random_lr()),
+                _ => BodyLocation::Fingers { count: 1.d3(), side: random_lr() }
+            }),
+            ..=5 => Self::Sickly,
+            6 => Self::Albino,
+            7 => Self::Blind(if 1.d4() == 1 {None} else {Some(random_lr())}),
+            8 => Self::Deaf(if 1.d4() == 1 {None} else {Some(random_lr())}),
+            9 => Self::Mute,
+            10 => Self::Hunchback,
+            11 => Self::ClubFoot(random_lr()),
+            12 => Self::Frail,
+            13 => Self::WeakHeart,
+            14 => Self::ExtraFingers { count: if lo!() {1} else {2}, side: random_lr() },
+            15 => Self::Harelip,
+            16 => Self::PoorEyesight,
+            17 => Self::Palsy,
+            18 => Self::Seizures,
+            19 => Self::Stunted,
+            _ => {
+                // roll twice more, rerolling any further 20s.
+                let mut afflictions: Vec<Self> = vec![];
+                while afflictions.len() < 2 {
+                    match Self::roll() {
+                        Self::Multiple(_) => continue,
+                        a => afflictions.push(a)
+                    }
+                }
+                Self::Multiple(afflictions)
+            }
+        };
+        roll_log::record("874", roll, &[], affliction.as_ref());
+        affliction
+    }
+
+    /// Stat penalties the affliction causes.
+    pub fn stats(&self) -> Vec<Stat> {
+        match self {
+            Self::Lame(_) |
+            Self::ClubFoot(_) => vec![Stat::Dex { val: -1 }],
+            Self::MissingLimb(BodyLocation::Fingers { .. }) => vec![Stat::Dex { val: -1 }],
+            Self::MissingLimb(_) => vec![Stat::Dex { val: -2 }],
+            Self::Sickly => vec![Stat::Con { val: -2 }],
+            Self::Blind(Some(_)) => vec![Stat::Dex { val: -1 }],
+            Self::Blind(None) => vec![Stat::Dex { val: -3 }],
+            Self::Mute => vec![Stat::Cha { val: -1 }],
+            Self::Hunchback => vec![Stat::App { val: -2 }, Stat::Dex { val: -1 }],
+            Self::Frail => vec![Stat::Str { val: -2 }],
+            Self::WeakHeart => vec![Stat::Con { val: -1 }],
+            Self::Harelip => vec![Stat::App { val: -2 }],
+            Self::Palsy => vec![Stat::Dex { val: -2 }],
+            Self::Stunted => vec![Stat::Str { val: -1 }, Stat::Con { val: -1 }],
+            Self::Multiple(afflictions) => afflictions.iter().flat_map(|a| a.stats()).collect(),
+            Self::Albino |
+            Self::Deaf(_) |
+            Self::ExtraFingers { .. } |
+            Self::PoorEyesight |
+            Self::Seizures => vec![]
+        }
+    }
+
+    /// Apply the affliction's stat penalties, if any, into `stats`.
+    pub fn apply_to(&self, stats: &mut StatMap) {
+        for stat in self.stats() {
+            *stats += stat;
+        }
+    }
+}
+
+impl Display for PhysicalAffliction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lame(lr) => write!(f, "lame in the {lr} leg"),
+            Self::MissingLimb(BodyLocation::Fingers { count, side }) => write!(f, "missing {count} finger{} from the {side} hand", if *count != 1 {"s"} else {""}),
+            Self::MissingLimb(bl) => write!(f, "missing a {bl}"),
+            Self::Sickly => write!(f, "sickly"),
+            Self::Albino => write!(f, "albino, sensitive to sunlight"),
+            Self::Blind(Some(lr)) => write!(f, "blind in the {lr} eye"),
+            Self::Blind(None) => write!(f, "blind"),
+            Self::Deaf(Some(lr)) => write!(f, "deaf in the {lr} ear"),
+            Self::Deaf(None) => write!(f, "deaf"),
+            Self::Mute => write!(f, "mute"),
+            Self::Hunchback => write!(f, "hunchbacked"),
+            Self::ClubFoot(lr) => write!(f, "club-footed in the {lr} foot"),
+            Self::Frail => write!(f, "frail"),
+            Self::WeakHeart => write!(f, "weak of heart"),
+            Self::ExtraFingers { count, side } => write!(f, "with {count} extra finger{} on the {side} hand", if *count != 1 {"s"} else {""}),
+            Self::Harelip => write!(f, "harelipped"),
+            Self::PoorEyesight => write!(f, "poor of eyesight"),
+            Self::Palsy => write!(f, "palsied"),
+            Self::Seizures => write!(f, "prone to seizures"),
+            Self::Stunted => write!(f, "stunted in growth"),
+            Self::Multiple(afflictions) => match afflictions.as_slice() {
+                [] => Ok(()),
+                [one] => write!(f, "{one}"),
+                [rest @ .., last] => write!(f, "{} and {last}", rest.iter()
+                    .map(|a| a.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod affliction_tests {
+    use crate::{racial::Race, social::culture::Culture};
+
+    use super::*;
+
+    #[test]
+    fn afflictions_apply_to_statmap() {
+        let mut workpad = Workpad::with_seed(874);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        let mut stats = workpad.get_statmap().clone();
+        let mut penalized = 0;
+        for _ in 0..100 {
+            let affliction = PhysicalAffliction::random(&mut workpad);
+            affliction.apply_to(&mut stats);
+            penalized += affliction.stats().len();
+            assert!(!affliction.to_string().is_empty());
+        }
+        assert!(penalized > 0);
+        assert_eq!(serde_jsonc::to_string(&stats).unwrap(), serde_jsonc::to_string(workpad.get_statmap()).unwrap());
+    }
+
+    #[test]
+    fn multiple_afflictions_do_not_nest() {
+        for _ in 0..1000 {
+            if let PhysicalAffliction::Multiple(afflictions) = PhysicalAffliction::roll() {
+                assert_eq!(2, afflictions.len());
+                assert!(!afflictions.iter().any(|a| matches!(a, PhysicalAffliction::Multiple(_))));
+            }
+        }
+    }
+}
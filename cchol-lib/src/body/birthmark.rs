@@ -1,11 +1,10 @@
 //! 866: Birthmarks
 use std::fmt::Display;
 
-use dicebag::DiceExt;
 use rpgassist::body::location::BodyLocation;
 use serde::{Deserialize, Serialize};
 
-use crate::misc::{ExoticColor, Shape};
+use crate::{dice::{DiceExt, random_body_location}, misc::{ExoticColor, Shape}};
 
 /// Birthmark specs live here.
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -19,7 +18,7 @@ pub struct Birthmark {
 impl Birthmark {
     /// Generate a random birthmark with location; also color if color is other than "natural".
     pub fn random() -> Self {
-        let location = BodyLocation::random();
+        let location = random_body_location();
         let exotic_color = if 1.d20() == 1 {Some(ExoticColor::random())} else {None};
         let shape = Shape::random();
 
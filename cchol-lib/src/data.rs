@@ -0,0 +1,299 @@
+//! Data file loading.
+//!
+//! All the tables get loaded lazily on first use, and a broken data file
+//! causes a panic at that point. Call [load_data] up front to get any and all
+//! such problems reported as a [CcholError] instead.
+//!
+//! Where the data comes from is up to [DataSource], selected once at startup
+//! with [set_data_source] — or left for us to figure out:
+//! `CCHOL_DATA_DIR` if set, the data set compiled in otherwise.
+//!
+//! # Data packs
+//!
+//! On top of the base data, a stack of homebrew data packs can be layered with
+//! [set_data_packs] (or `CCHOL_DATA_PACKS`). A pack is a directory with any of
+//! the data files in it, and each file gets merged over the base one, in pack
+//! order:
+//!
+//! * entries (in arrays) are matched by `name` — same name replaces the entry,
+//!   new name adds one, and `"_remove": true` removes the named entry;
+//! * objects get merged key by key, anything else just gets replaced.
+//!
+//! ```jsonc
+//! // homebrew/race.json
+//! [
+//!     { "name": "elf", "_cr_range": [3361,3700] },
+//!     { "name": "kobold", "_cr_range": [3701,3840] },
+//!     { "name": "dwarf", "_remove": true }
+//! ]
+//! ```
+
+use std::{borrow::Cow, collections::BTreeMap, env, fs, path::{Path, PathBuf}, sync::{Mutex, OnceLock}};
+
+use serde::de::DeserializeOwned;
+use serde_jsonc::Value;
+
+use crate::{animal, error::{CcholError, Result}, events, misc, places, racial, skill, social, traits};
+
+/// Env var to point at a data directory.
+pub const DATA_DIR_ENV: &str = "CCHOL_DATA_DIR";
+
+/// Env var listing data pack directories, separated like `PATH` is.
+pub const DATA_PACKS_ENV: &str = "CCHOL_DATA_PACKS";
+
+static DATA_SOURCE: OnceLock<DataSource> = OnceLock::new();
+static DATA_PACKS: OnceLock<Vec<PathBuf>> = OnceLock::new();
+// What the data packs did to which file, for diagnostics.
+static PACK_NOTES: Mutex<BTreeMap<String, Vec<String>>> = Mutex::new(BTreeMap::new());
+
+/// The data set shipped along, compiled right in.
+static EMBEDDED: &[(&str, &str)] = &[
+    ("adventurer.json",       include_str!("../data/adventurer.json")),
+    ("animal.json",           include_str!("../data/animal.json")),
+    ("blessing.json",         include_str!("../data/blessing.json")),
+    ("color.json",            include_str!("../data/color.json")),
+    ("criminals.json",        include_str!("../data/criminals.json")),
+    ("culture.json",          include_str!("../data/culture.json")),
+    ("curse.json",            include_str!("../data/curse.json")),
+    ("death.json",            include_str!("../data/death.json")),
+    ("deity.json",            include_str!("../data/deity.json")),
+    ("ebloc.json",            include_str!("../data/ebloc.json")),
+    ("gifts_n_legacies.json", include_str!("../data/gifts_n_legacies.json")),
+    ("land_titles.json",      include_str!("../data/land_titles.json")),
+    ("monsters.json",         include_str!("../data/monsters.json")),
+    ("nobility.json",         include_str!("../data/nobility.json")),
+    ("occupation.json",       include_str!("../data/occupation.json")),
+    ("people_govtoff.json",   include_str!("../data/people_govtoff.json")),
+    ("personality.json",      include_str!("../data/personality.json")),
+    ("personality_al.json",   include_str!("../data/personality_al.json")),
+    ("personality_bt.json",   include_str!("../data/personality_bt.json")),
+    ("personality_ph.json",   include_str!("../data/personality_ph.json")),
+    ("psionic.json",          include_str!("../data/psionic.json")),
+    ("race.json",             include_str!("../data/race.json")),
+    ("shape.json",            include_str!("../data/shape.json")),
+    ("skill.json",            include_str!("../data/skill.json")),
+    ("wealth.json",           include_str!("../data/wealth.json")),
+    ("work_attitude.json",    include_str!("../data/work_attitude.json")),
+];
+
+/// Where to load the data files from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataSource {
+    /// A directory with all the `*.json` files in it.
+    Dir(PathBuf),
+    /// The data set compiled into the binary.
+    Embedded,
+} impl DataSource {
+    /// `CCHOL_DATA_DIR` if set, [Embedded][DataSource::Embedded] otherwise.
+    pub fn from_env() -> Self {
+        match env::var_os(DATA_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => Self::Dir(dir.into()),
+            _ => Self::Embedded
+        }
+    }
+
+    /// Read raw contents of data `file`.
+    fn read(&self, file: &str) -> Result<Cow<'static, str>> {
+        match self {
+            Self::Dir(dir) => fs::read_to_string(dir.join(file))
+                .map(Cow::Owned)
+                .map_err(|e| CcholError::DataFile { file: dir.join(file).display().to_string(), reason: e.to_string() }),
+            Self::Embedded => EMBEDDED.iter()
+                .find(|(name, _)| *name == file)
+                .map(|(_, json)| Cow::Borrowed(*json))
+                .ok_or_else(|| CcholError::DataFile { file: file.into(), reason: "not embedded".into() })
+        }
+    }
+}
+
+/// Select the [DataSource] to use — once, and before any data gets loaded.
+pub fn set_data_source(source: DataSource) -> Result<()> {
+    if let DataSource::Dir(dir) = &source && !dir.is_dir() {
+        return Err(CcholError::DataFile { file: dir.display().to_string(), reason: "no such directory".into() });
+    }
+    DATA_SOURCE.set(source).map_err(|_| CcholError::DataSourceLocked)
+}
+
+/// Get the [DataSource] in use.
+pub fn data_source() -> &'static DataSource {
+    DATA_SOURCE.get_or_init(DataSource::from_env)
+}
+
+/// Select the data packs to layer over the base data — once, and before any data gets loaded.
+/// 
+/// Later packs win over earlier ones.
+pub fn set_data_packs(packs: Vec<PathBuf>) -> Result<()> {
+    if let Some(dir) = packs.iter().find(|p| !p.is_dir()) {
+        return Err(CcholError::DataFile { file: dir.display().to_string(), reason: "no such data pack directory".into() });
+    }
+    DATA_PACKS.set(packs).map_err(|_| CcholError::DataSourceLocked)
+}
+
+/// Get the data packs in use, if any.
+pub fn data_packs() -> &'static [PathBuf] {
+    DATA_PACKS.get_or_init(|| env::var_os(DATA_PACKS_ENV)
+        .map(|packs| env::split_paths(&packs).filter(|p| !p.as_os_str().is_empty()).collect())
+        .unwrap_or_default())
+}
+
+/// Parse `json` from `file`.
+fn parse<T: DeserializeOwned>(file: &str, json: &str) -> Result<T> {
+    serde_jsonc::from_str(json)
+        .map_err(|e| CcholError::DataFile { file: file.into(), reason: e.to_string() })
+}
+
+/// Load and parse a JSON(C) data `file`, with data packs (if any) merged in.
+pub(crate) fn load_json<T: DeserializeOwned>(file: &str) -> Result<T> {
+    let json = data_source().read(file)?;
+    let packs = data_packs();
+    if packs.is_empty() {
+        return parse(file, &json);
+    }
+
+    let mut merged: Value = parse(file, &json)?;
+    for pack in packs {
+        let path = pack.join(file);
+        if !path.is_file() {
+            continue;
+        }
+        let layer = fs::read_to_string(&path)
+            .map_err(|e| CcholError::DataFile { file: path.display().to_string(), reason: e.to_string() })?;
+        let mut notes = vec![];
+        merge(&mut merged, parse(&path.display().to_string(), &layer)?, &mut notes);
+        log::debug!("Data pack '{}' applied to '{file}': {}", pack_name(pack), notes.join(", "));
+        PACK_NOTES.lock().unwrap()
+            .entry(file.into()).or_default()
+            .push(format!("{} ({})", pack_name(pack), notes.join(", ")));
+    }
+    serde_jsonc::from_value(merged)
+        .map_err(|e| blame_packs(file)(CcholError::DataFile { file: file.into(), reason: e.to_string() }))
+}
+
+/// Get a readable name for a data `pack`.
+fn pack_name(pack: &Path) -> String {
+    pack.file_name().unwrap_or(pack.as_os_str()).to_string_lossy().into()
+}
+
+/// Get the name of an entry, if it has one.
+/// 
+/// Names come as `"name": "X"`, `"name": ["X", "Y"]` (gendered), or just `"X"`.
+fn entry_name(entry: &Value) -> Option<String> {
+    match entry {
+        Value::String(s) => Some(s.to_lowercase()),
+        Value::Object(o) => match o.get("name")? {
+            Value::String(s) => Some(s.to_lowercase()),
+            Value::Array(a) => a.first()?.as_str().map(str::to_lowercase),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+/// Merge `layer` over `base`, noting down what was done.
+fn merge(base: &mut Value, layer: Value, notes: &mut Vec<String>) {
+    match (base, layer) {
+        (Value::Array(base), Value::Array(layer)) => for entry in layer {
+            let Some(name) = entry_name(&entry) else {
+                base.push(entry);
+                continue;
+            };
+            if entry.get("_remove").and_then(Value::as_bool).unwrap_or(false) {
+                let count = base.len();
+                base.retain(|e| entry_name(e).as_ref() != Some(&name));
+                if count == base.len() {
+                    log::warn!("Nothing called '{name}' to remove!");
+                }
+                notes.push(format!("-{name}"));
+            } else if let Some(existing) = base.iter_mut().find(|e| entry_name(e).as_ref() == Some(&name)) {
+                *existing = entry;
+                notes.push(format!("~{name}"));
+            } else {
+                base.push(entry);
+                notes.push(format!("+{name}"));
+            }
+        },
+        (Value::Object(base), Value::Object(layer)) => for (key, value) in layer {
+            match base.get_mut(&key) {
+                Some(existing) => merge(existing, value, notes),
+                None => { base.insert(key, value); }
+            }
+        },
+        (base, layer) => *base = layer
+    }
+}
+
+/// Point a finger at the data packs that touched `file`, if any, when validation fails.
+pub(crate) fn blame_packs(file: &str) -> impl Fn(CcholError) -> CcholError {
+    move |e| {
+        let notes = PACK_NOTES.lock().unwrap();
+        let Some(notes) = notes.get(file) else { return e };
+        let blame = format!("\n  data packs applied to '{file}': {}", notes.join("; "));
+        match e {
+            CcholError::Validation { table, reason } => CcholError::Validation { table, reason: reason + &blame },
+            CcholError::DataFile { file, reason } => CcholError::DataFile { file, reason: reason + &blame },
+            e => e
+        }
+    }
+}
+
+/// Get a reference to (successfully) loaded data.
+///
+/// # Panic
+/// Panics if loading had failed — [load_data] is there to find that out beforehand.
+pub(crate) fn loaded<T>(data: &'static Result<T>) -> &'static T {
+    data.as_ref().unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Check how loading some data went.
+pub(crate) fn check<T>(data: &'static Result<T>) -> Result<&'static T> {
+    data.as_ref().map_err(Clone::clone)
+}
+
+/// Load and validate all the data files.
+///
+/// Doing this is optional — everything gets loaded on demand anyway — but
+/// any data problems will cause a panic later on if not caught here.
+pub fn load_data() -> Result<()> {
+    racial::try_load()?;
+    social::try_load()?;
+    places::birthplace::try_load()?;
+    skill::try_load()?;
+    misc::try_load()?;
+    animal::try_load()?;
+    events::try_load()?;
+    traits::personality::try_load()
+}
+
+#[cfg(test)]
+mod data_tests {
+    use super::*;
+
+    #[test]
+    fn merge_by_name() {
+        let mut base: Value = serde_jsonc::from_str(r#"[
+            {"name": "elf", "_cr_range": [1,5]},
+            {"name": ["High King", "High Queen"], "_cr_range": 6},
+            {"name": "dwarf", "_cr_range": [7,10]}
+        ]"#).unwrap();
+        let layer: Value = serde_jsonc::from_str(r#"[
+            {"name": "Elf", "_cr_range": [1,4]},
+            {"name": "kobold", "_cr_range": 5},
+            {"name": "dwarf", "_remove": true}
+        ]"#).unwrap();
+        let mut notes = vec![];
+        merge(&mut base, layer, &mut notes);
+        assert_eq!(vec!["~elf", "+kobold", "-dwarf"], notes);
+        let names: Vec<_> = base.as_array().unwrap().iter().filter_map(entry_name).collect();
+        assert_eq!(vec!["elf", "high king", "kobold"], names);
+    }
+
+    #[test]
+    fn merge_objects_by_key() {
+        let mut base: Value = serde_jsonc::from_str(r#"{"chooser": "d100", "titles": [{"name": "Emperor"}]}"#).unwrap();
+        let layer: Value = serde_jsonc::from_str(r#"{"titles": [{"name": "Warlord"}]}"#).unwrap();
+        let mut notes = vec![];
+        merge(&mut base, layer, &mut notes);
+        assert_eq!("d100", base["chooser"]);
+        assert_eq!(2, base["titles"].as_array().unwrap().len());
+    }
+}
@@ -0,0 +1,207 @@
+//! Seedable dice.
+//!
+//! Mostly a drop-in for `dicebag`'s [DiceExt], [RandomOf], etc., except that
+//! the RNG behind all the rolls is a per-thread one that can be (re)seeded.
+//! Same seed, same options, same data files → same character, every time.
+//!
+//! Also houses seeded stand-ins for `rpgassist`'s own randomizers
+//! (e.g. [Gender::random]) as those roll with `dicebag`'s unseedable RNG.
+
+use std::{cell::RefCell, ops::RangeInclusive, time::{SystemTime, UNIX_EPOCH}};
+
+use rand_xoshiro::{Xoshiro256PlusPlus, rand_core::{Rng, SeedableRng}};
+use rpgassist::{body::location::BodyLocation, direction::bilateral::Bilateral, gender::{Bias10, Gender, GenderBias}};
+
+thread_local! {
+    // Workpad is single-thread-only, thus a per-thread RNG is all we need.
+    static RNG: RefCell<Xoshiro256PlusPlus> = RefCell::new(Xoshiro256PlusPlus::seed_from_u64(random_seed()));
+}
+
+/// (Re)seed the dice of the current thread.
+pub fn reseed(seed: u64) {
+    RNG.with_borrow_mut(|rng| *rng = Xoshiro256PlusPlus::seed_from_u64(seed));
+}
+
+/// Get a fresh seed out of thin air (well, out of system clock).
+pub fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time travel detected — system clock is set before 1970?!")
+        .as_nanos() as u64
+}
+
+/// Roll a single die with `sides` sides.
+fn roll_one(sides: u64) -> u64 {
+    if sides == 0 {
+        return 0;
+    }
+    let x = RNG.with_borrow_mut(|rng| rng.next_u64());
+    (((x as u128) * (sides as u128)) >> 64) as u64 + 1
+}
+
+/// Dice rolling à la `1.d20()`.
+pub trait DiceExt {
+    /// Roll `self` number of dice with `sides` sides and sum them up.
+    fn d(&self, sides: usize) -> Self;
+    fn d2(&self) -> Self where Self: Sized { self.d(2) }
+    fn d3(&self) -> Self where Self: Sized { self.d(3) }
+    fn d4(&self) -> Self where Self: Sized { self.d(4) }
+    fn d5(&self) -> Self where Self: Sized { self.d(5) }
+    fn d6(&self) -> Self where Self: Sized { self.d(6) }
+    fn d8(&self) -> Self where Self: Sized { self.d(8) }
+    fn d10(&self) -> Self where Self: Sized { self.d(10) }
+    fn d12(&self) -> Self where Self: Sized { self.d(12) }
+    fn d20(&self) -> Self where Self: Sized { self.d(20) }
+    fn d100(&self) -> Self where Self: Sized { self.d(100) }
+}
+
+macro_rules! implement_diceext {
+    ($($t:ty),*) => {$(
+        impl DiceExt for $t {
+            fn d(&self, sides: usize) -> Self {
+                // zero or less dice → nothing to roll.
+                let count = (*self as i128).max(0) as u64;
+                (0..count).map(|_| roll_one(sides as u64)).sum::<u64>() as $t
+            }
+        }
+    )*};
+}
+implement_diceext!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Pick a random entry of something.
+pub trait RandomOf<T> {
+    /// Get a random (cloned) entry.
+    ///
+    /// # Panic
+    /// Empty source will cause a panic.
+    fn random_of(&self) -> T;
+}
+
+impl <T: Clone> RandomOf<T> for [T] {
+    fn random_of(&self) -> T {
+        if self.is_empty() { panic!("Empty collection - can't pick a random from that!") }
+        self[1.d(self.len()) - 1].clone()
+    }
+}
+
+impl <T: Clone> RandomOf<T> for Vec<T> {
+    fn random_of(&self) -> T {
+        self.as_slice().random_of()
+    }
+}
+
+impl <T: Clone, const N: usize> RandomOf<T> for [T;N] {
+    fn random_of(&self) -> T {
+        self.as_slice().random_of()
+    }
+}
+
+/// Pick a random value within an inclusive range.
+pub trait InclusiveRandomRange<T> {
+    fn random_of(&self) -> T;
+}
+
+impl InclusiveRandomRange<i32> for RangeInclusive<i32> {
+    fn random_of(&self) -> i32 {
+        let span = (*self.end() as i64 - *self.start() as i64 + 1).max(1) as usize;
+        self.start() + 1_i32.d(span) - 1
+    }
+}
+
+/// 50% chance of `true`.
+macro_rules! lo { () => {{ use $crate::dice::DiceExt; 1_u8.d2() == 1 }}; }
+/// 50% chance of `true`.
+#[allow(unused_macros)]
+macro_rules! hi { () => {{ use $crate::dice::DiceExt; 1_u8.d2() == 2 }}; }
+/// `$chance`% of `$v`, otherwise `0`.
+macro_rules! percentage_chance_of {
+    ($chance:expr, $v:expr) => {{
+        use $crate::dice::DiceExt;
+        if 1_i32.d100() <= $chance as i32 { $v } else { 0 }
+    }};
+}
+#[allow(unused_imports)]
+pub(crate) use {hi, lo, percentage_chance_of};
+
+/// Seeded [Gender::random].
+pub(crate) fn random_gender() -> Gender {
+    random_gender_biased(GenderBias::None)
+}
+
+/// Seeded [Gender::random_biased].
+pub(crate) fn random_gender_biased(bias: GenderBias) -> Gender {
+    if 1.d20() + match bias {
+        GenderBias::Male23 => -3,
+        GenderBias::Male(v) => -bias10_value(&v),
+        GenderBias::Female23 => 4,
+        GenderBias::Female(v) => bias10_value(&v),
+        _ => 0
+    } <= 10 { Gender::Male }
+    else { Gender::Female }
+}
+
+/// Seeded [Gender::resolve_biased].
+pub(crate) fn resolve_gender_biased(gender: &mut Gender, bias: GenderBias) {
+    if matches!(gender, Gender::Unspecified) {
+        *gender = random_gender_biased(bias)
+    }
+}
+
+/// [Bias10] keeps its value to itself, so we read it off of its [Debug] output.
+fn bias10_value(bias: &Bias10) -> i32 {
+    format!("{bias:?}").chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0)
+}
+
+/// Seeded [Bilateral::random_lr].
+pub(crate) fn random_lr() -> Bilateral {
+    if lo!() { Bilateral::Left } else { Bilateral::Right }
+}
+
+/// Seeded [BodyLocation::random].
+pub(crate) fn random_body_location() -> BodyLocation {
+    match 1.d20() {
+        ..=1 => BodyLocation::Foot(Bilateral::Right),
+        2 => BodyLocation::Foot(Bilateral::Left),
+        3 => BodyLocation::Leg(Bilateral::Right),
+        4 => BodyLocation::Leg(Bilateral::Left),
+        5|6 => BodyLocation::Abdomen,
+        7 => BodyLocation::Buttocks,
+        8 => if 1.d3() == 1 { BodyLocation::Genitals } else { BodyLocation::Buttocks },
+        9 => BodyLocation::Back,
+        10..=13 => BodyLocation::Chest,
+        14 => BodyLocation::Arm(Bilateral::Right),
+        15 => BodyLocation::Arm(Bilateral::Left),
+        16 => BodyLocation::Hand(Bilateral::Right),
+        17 => BodyLocation::Hand(Bilateral::Left),
+        18 => BodyLocation::Head,
+        _ => BodyLocation::Face
+    }
+}
+
+#[cfg(test)]
+mod dice_tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_rolls() {
+        reseed(1234);
+        let a: Vec<i32> = (0..100).map(|_| 1.d100()).collect();
+        reseed(1234);
+        let b: Vec<i32> = (0..100).map(|_| 1.d100()).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn rolls_stay_in_range() {
+        (0..1000).for_each(|_| {
+            let r = 3.d6();
+            assert!((3..=18).contains(&r));
+            let r = (-5..=5).random_of();
+            assert!((-5..=5).contains(&r));
+        });
+    }
+}
@@ -0,0 +1,43 @@
+//! The one and only error type of the library — [CcholError].
+//!
+//! Most of the API has a panicky variant (e.g. [Race::from][crate::racial::Race::from])
+//! for when a failure means a bug somewhere, and a fallible `try_*` variant
+//! (e.g. [Race::try_from_name][crate::racial::Race::try_from_name]) for when
+//! the input comes from a user and might well be garbage.
+
+use std::fmt::Display;
+
+/// Anything and everything that can go wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CcholError {
+    /// No `kind` (race, culture, skill, …) called `name` exists.
+    UnknownName { kind: &'static str, name: String },
+    /// A data file is missing, unreadable, or its JSON is malformed.
+    DataFile { file: String, reason: String },
+    /// Data got loaded fine, but didn't pass validation (e.g. `_cr_range` gaps/overlaps).
+    Validation { table: String, reason: String },
+    /// [Workpad][crate::Workpad] got asked for `what` before it was determined.
+    OutOfSequence { what: &'static str },
+    /// [DataSource][crate::data::DataSource] was already selected (or put in use) earlier.
+    DataSourceLocked,
+    /// A [save file][crate::save] is unreadable, malformed, or from the future.
+    SaveFile { reason: String },
+}
+
+impl Display for CcholError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownName { kind, name } => write!(f, "No {kind} called '{name}' found!"),
+            Self::DataFile { file, reason } => write!(f, "DATA FILE '{file}': {reason}"),
+            Self::Validation { table, reason } => write!(f, "DATA VALIDATION ({table}): {reason}"),
+            Self::OutOfSequence { what } => write!(f, "`{what}` not yet determined!"),
+            Self::DataSourceLocked => write!(f, "Data source already selected!"),
+            Self::SaveFile { reason } => write!(f, "SAVE FILE: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CcholError {}
+
+/// Shorthand for [Result][std::result::Result] with [CcholError].
+pub type Result<T, E = CcholError> = std::result::Result<T, E>;
@@ -1,4 +1,27 @@
+mod blessing;
+pub use blessing::Blessing;
+mod curse;
+pub use curse::{Curse, CurseSeverity};
+mod death;
+pub use death::DeathSituation;
+pub mod gifts_n_legacies;
+pub use gifts_n_legacies::GiftOrLegacy;
+mod psionic;
+pub use psionic::{PsionicAbility, PsionicControl};
 mod racial_events;
 pub use racial_events::RacialEvent;
+mod tragedy;
+pub use tragedy::Tragedy;
 mod unusual_birth_circumstances;
-pub use unusual_birth_circumstances::UnusualBirthCircumstance;
\ No newline at end of file
+pub use unusual_birth_circumstances::{Ubc3941, Ubc4950, UnusualBirthCircumstance};
+
+use crate::error::Result;
+
+/// Load and validate all the event data.
+pub(crate) fn try_load() -> Result<()> {
+    blessing::try_load()?;
+    curse::try_load()?;
+    death::try_load()?;
+    gifts_n_legacies::try_load()?;
+    psionic::try_load()
+}
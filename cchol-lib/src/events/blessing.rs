@@ -0,0 +1,151 @@
+//! 868: Blessings
+use std::fmt::Display;
+
+use cchol_pm::HasRollRange;
+use lazy_static::lazy_static;
+use rpgassist::{ext::IsNamed, stat::Stat};
+use serde::{Deserialize, Serialize};
+
+use crate::{Workpad, data::{blame_packs, check, load_json, loaded}, error::Result, roll_log, roll_range::*, serialize::{deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}, skill::SkillBase, social::Deity};
+
+static BLESSING_FILE: &'static str = "blessing.json";
+lazy_static! {
+    static ref BLESSINGS_DATA: Result<Vec<BlessingNote>> = load_json(BLESSING_FILE);
+    static ref BLESSINGS: &'static Vec<BlessingNote> = loaded(&BLESSINGS_DATA);
+
+    static ref BLESSINGS_RANGE: RollRange = validate_cr_ranges("BLESSINGS", &BLESSINGS, None);
+}
+
+/// Load and validate [Blessing] data.
+pub(crate) fn try_load() -> Result<()> {
+    let blessings = check(&BLESSINGS_DATA)?;
+    try_validate_cr_ranges("BLESSINGS", blessings, None).map_err(blame_packs(BLESSING_FILE))?;
+    for (skill, _) in blessings.iter().flat_map(|b| &b.skills) {
+        SkillBase::try_from_name(skill).map_err(blame_packs(BLESSING_FILE))?;
+    }
+    Ok(())
+}
+
+/// Blessing table entry.
+#[derive(Debug, Deserialize, Clone, HasRollRange)]
+struct BlessingNote {
+    name: String,
+    /// Is the blessing from a deity?
+    #[serde(default)]
+    deity: bool,
+    #[serde(default)]
+    stats: Vec<Stat>,
+    #[serde(default)]
+    skills: Vec<(String, i32)>,
+    #[serde(deserialize_with = "deserialize_cr_range")]
+    _cr_range: RollRange,
+}
+
+/// A blessing bestowed upon someone.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Blessing {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deity: Option<Deity>,
+    /// Stat changes, already applied to the blessed one's stats.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    stats: Vec<Stat>,
+    /// Skills (and their ranks), already granted to the blessed one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skills: Vec<(String, i32)>,
+} impl Blessing {
+    /// Generate a random [Blessing] and apply its stat and skill effects, if any, into `workpad`.
+    pub fn random(workpad: &mut Workpad) -> Self {
+        Self::random_by(None, workpad)
+    }
+
+    /// Generate a random [Blessing] like [random][Blessing::random] does, but
+    /// should it be a deity's to give, it is by grace of `deity`.
+    pub fn random_from(deity: &Deity, workpad: &mut Workpad) -> Self {
+        Self::random_by(Some(deity), workpad)
+    }
+
+    fn random_by(deity: Option<&Deity>, workpad: &mut Workpad) -> Self {
+        let (roll, note) = BLESSINGS.roll_in_range(&BLESSINGS_RANGE);
+        roll_log::record("868", roll, &[], note.name());
+        let blessing = Self {
+            name: note.name.clone(),
+            deity: note.deity.then(|| deity.cloned().unwrap_or_else(|| Deity::random(workpad))),
+            stats: note.stats.clone(),
+            skills: note.skills.clone(),
+        };
+        for stat in &blessing.stats {
+            *workpad += stat.clone();
+        }
+        for (skill, rank) in &blessing.skills {
+            *workpad += (SkillBase::try_from_name(skill).unwrap_or_else(|e| panic!("{e}")), *rank);
+        }
+        blessing
+    }
+
+    /// Get the blessing [Deity], if any.
+    pub fn deity(&self) -> Option<&Deity> {
+        self.deity.as_ref()
+    }
+
+    /// Get the stat changes the blessing causes.
+    pub fn stats(&self) -> &[Stat] {
+        &self.stats
+    }
+
+    /// Get the skills (and their ranks) the blessing grants.
+    pub fn skills(&self) -> &[(String, i32)] {
+        &self.skills
+    }
+}
+
+impl IsNamed for BlessingNote {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IsNamed for Blessing {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for Blessing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.deity {
+            None => write!(f, "blessing of {}", self.name),
+            Some(deity) => write!(f, "blessing of {}, by grace of the {} faith", self.name, deity.name())
+        }
+    }
+}
+
+#[cfg(test)]
+mod blessing_tests {
+    use crate::{racial::Race, social::culture::Culture};
+
+    use super::*;
+
+    #[test]
+    fn blessing_file_data_integrity() {
+        try_load().unwrap();
+        assert!(BLESSINGS.iter().any(|b| b.deity));
+    }
+
+    #[test]
+    fn blessings_apply_to_workpad() {
+        let mut workpad = Workpad::with_seed(868);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        let mut stats = workpad.get_statmap().clone();
+        let mut skills = 0;
+        for _ in 0..100 {
+            let blessing = Blessing::random(&mut workpad);
+            blessing.stats().iter().for_each(|s| stats += s.clone());
+            skills += blessing.skills().len();
+            assert_eq!(blessing.deity().is_some(), blessing.to_string().contains("faith"));
+        }
+        assert_eq!(serde_jsonc::to_string(&stats).unwrap(), serde_jsonc::to_string(workpad.get_statmap()).unwrap());
+        assert!(skills > 0 && !workpad.get_skills().is_empty());
+    }
+}
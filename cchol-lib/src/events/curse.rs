@@ -0,0 +1,175 @@
+//! 867: Curses
+use std::fmt::Display;
+
+use cchol_pm::HasRollRange;
+use lazy_static::lazy_static;
+use rpgassist::{ext::IsNamed, stat::Stat};
+use serde::{Deserialize, Serialize};
+
+use crate::{Workpad, data::{blame_packs, check, load_json, loaded}, dice::RandomOf, error::{CcholError, Result}, roll_log, roll_range::*, serialize::{deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}, string_manip::{resolve_name_hooks, try_check_name_hooks}};
+
+static CURSE_FILE: &'static str = "curse.json";
+lazy_static! {
+    static ref CURSEFILE_DATA: Result<CurseFile> = load_json(CURSE_FILE);
+    static ref CURSEFILE: &'static CurseFile = loaded(&CURSEFILE_DATA);
+
+    static ref CURSES_RANGE: RollRange = validate_cr_ranges("CURSES", &CURSEFILE.curses, None);
+}
+
+/// Load and validate [Curse] data.
+pub(crate) fn try_load() -> Result<()> {
+    let file = check(&CURSEFILE_DATA)?;
+    try_validate_cr_ranges("CURSES", &file.curses, None).map_err(blame_packs(CURSE_FILE))?;
+    for (what, list) in [("casters", &file.casters), ("reasons", &file.reasons), ("lifted_by", &file.lifted_by)] {
+        if list.is_empty() {
+            return Err(CcholError::Validation { table: CURSE_FILE.into(), reason: format!("'{what}' is empty") });
+        }
+        for name in list {
+            try_check_name_hooks(CURSE_FILE, name).map_err(blame_packs(CURSE_FILE))?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct CurseFile {
+    curses: Vec<CurseNote>,
+    casters: Vec<String>,
+    reasons: Vec<String>,
+    lifted_by: Vec<String>,
+}
+
+/// How bad a [Curse] is.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CurseSeverity {
+    Minor,
+    Major,
+    Grievous,
+}
+
+impl Display for CurseSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Minor => "minor",
+            Self::Major => "major",
+            Self::Grievous => "grievous",
+        })
+    }
+}
+
+/// Curse table entry.
+#[derive(Debug, Deserialize, Clone, HasRollRange)]
+struct CurseNote {
+    name: String,
+    severity: CurseSeverity,
+    #[serde(default)]
+    stats: Vec<Stat>,
+    #[serde(deserialize_with = "deserialize_cr_range")]
+    _cr_range: RollRange,
+}
+
+/// A curse laid upon someone.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Curse {
+    name: String,
+    severity: CurseSeverity,
+    /// Stat changes, already applied to the cursed one's stats.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    stats: Vec<Stat>,
+    caster: String,
+    reason: String,
+    lifted_by: String,
+} impl Curse {
+    /// Generate a random [Curse] and apply its stat changes, if any, into `workpad`.
+    pub fn random(workpad: &mut Workpad) -> Self {
+        let (roll, note) = CURSEFILE.curses.roll_in_range(&CURSES_RANGE);
+        roll_log::record("867", roll, &[], note.name());
+        let curse = Self {
+            name: note.name.clone(),
+            severity: note.severity,
+            stats: note.stats.clone(),
+            caster: resolve_name_hooks(&CURSEFILE.casters.random_of(), workpad),
+            reason: resolve_name_hooks(&CURSEFILE.reasons.random_of(), workpad),
+            lifted_by: resolve_name_hooks(&CURSEFILE.lifted_by.random_of(), workpad),
+        };
+        for stat in &curse.stats {
+            *workpad += stat.clone();
+        }
+        curse
+    }
+
+    /// Get [severity][CurseSeverity].
+    pub fn severity(&self) -> CurseSeverity {
+        self.severity
+    }
+
+    /// Get the stat changes the curse causes.
+    pub fn stats(&self) -> &[Stat] {
+        &self.stats
+    }
+
+    /// Who cast the curse?
+    pub fn caster(&self) -> &str {
+        &self.caster
+    }
+
+    /// Why was the curse cast?
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// How can the curse be lifted (if at all)?
+    pub fn lifted_by(&self) -> &str {
+        &self.lifted_by
+    }
+}
+
+impl IsNamed for CurseNote {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IsNamed for Curse {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for Curse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} curse)", self.name, self.severity)
+    }
+}
+
+#[cfg(test)]
+mod curse_tests {
+    use crate::{racial::Race, social::culture::Culture};
+
+    use super::*;
+
+    #[test]
+    fn curse_file_data_integrity() {
+        try_load().unwrap();
+        assert!(CURSEFILE.curses.iter().any(|c| !c.stats.is_empty()));
+    }
+
+    #[test]
+    fn curse_stats_apply_to_statmap() {
+        let mut workpad = Workpad::with_seed(867);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        let mut before = workpad.get_statmap().clone();
+        let curse = (0..1000)
+            .map(|_| Curse::random(&mut workpad))
+            .find(|c| !c.stats().is_empty())
+            .expect("No stat-changing curses in 1000 rolls?!");
+        for stat in curse.stats() {
+            before += stat.clone();
+        }
+        for stat in curse.stats() {
+            assert_eq!(before.get(stat.stat_base()), workpad.get_statmap().get(stat.stat_base()));
+        }
+        assert!(!curse.caster().contains('<'));
+    }
+}
@@ -0,0 +1,111 @@
+//! Death situations — for prophecies of death and those who die in tragedies alike.
+use std::fmt::Display;
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use crate::{data::{check, load_json, loaded}, dice::RandomOf, error::{CcholError, Result}};
+
+static DEATH_FILE: &'static str = "death.json";
+lazy_static! {
+    static ref DEATHFILE_DATA: Result<DeathFile> = load_json(DEATH_FILE);
+    static ref DEATHFILE: &'static DeathFile = loaded(&DEATHFILE_DATA);
+}
+
+/// Load and validate [DeathSituation] data.
+pub(crate) fn try_load() -> Result<()> {
+    let file = check(&DEATHFILE_DATA)?;
+    for (what, list) in [("manners", &file.manners), ("places", &file.places), ("circumstances", &file.circumstances)] {
+        if list.is_empty() {
+            return Err(CcholError::Validation { table: DEATH_FILE.into(), reason: format!("'{what}' is empty") });
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct DeathFile {
+    manners: Vec<String>,
+    places: Vec<String>,
+    circumstances: Vec<String>,
+}
+
+/// How, where and in what circumstances someone dies (or is foretold to die).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DeathSituation {
+    manner: String,
+    place: String,
+    circumstance: String,
+    /// Known only to the GM?
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    hidden: bool,
+} impl DeathSituation {
+    /// Generate a random [DeathSituation].
+    pub fn random() -> Self {
+        Self {
+            manner: DEATHFILE.manners.random_of(),
+            place: DEATHFILE.places.random_of(),
+            circumstance: DEATHFILE.circumstances.random_of(),
+            hidden: false,
+        }
+    }
+
+    /// Hide the death situation from the player — it's for the GM to know.
+    ///
+    /// Chainable.
+    pub fn hide(&mut self) -> &mut Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Is the death situation hidden from the player?
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Manner of death, e.g. "drowning".
+    pub fn manner(&self) -> &str {
+        &self.manner
+    }
+
+    /// Place of death, e.g. "at sea".
+    pub fn place(&self) -> &str {
+        &self.place
+    }
+
+    /// Circumstances of death, e.g. "while saving another".
+    pub fn circumstance(&self) -> &str {
+        &self.circumstance
+    }
+
+    /// The death situation as the player gets to know it: in full, or not at all if [hidden][Self::is_hidden].
+    pub fn player_view(&self) -> Option<String> {
+        (!self.hidden).then(|| self.to_string())
+    }
+}
+
+impl Display for DeathSituation {
+    /// Full narrative, e.g. "by drowning, at sea, while saving another" — hidden or not.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "by {}, {}, {}", self.manner, self.place, self.circumstance)
+    }
+}
+
+#[cfg(test)]
+mod death_tests {
+    use super::*;
+
+    #[test]
+    fn death_file_data_integrity() {
+        try_load().unwrap();
+    }
+
+    #[test]
+    fn hidden_from_player() {
+        let mut death = DeathSituation::random();
+        assert_eq!(Some(death.to_string()), death.player_view());
+        assert!(death.hide().is_hidden());
+        assert_eq!(None, death.player_view());
+        assert!(death.to_string().starts_with("by "));
+    }
+}
@@ -0,0 +1,225 @@
+//! 863: Gifts & Legacies
+use std::{collections::BTreeSet, fmt::Display};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
+
+use crate::{Workpad, data::{blame_packs, check, load_json, loaded}, dice::{DiceExt, RandomOf}, error::{CcholError, Result}, misc::{ExoticColor, Substance}, roll_log, social::people::{OtherPeople, Relation}};
+
+static GIFTS_FILE: &'static str = "gifts_n_legacies.json";
+lazy_static! {
+    static ref GIFTSFILE_DATA: Result<GiftsFile> = load_json(GIFTS_FILE);
+    static ref GIFTSFILE: &'static GiftsFile = loaded(&GIFTSFILE_DATA);
+}
+
+/// Load and validate [GiftOrLegacy] data.
+pub(crate) fn try_load() -> Result<()> {
+    let file = check(&GIFTSFILE_DATA)?;
+    for (what, list) in [
+        ("weapons", &file.weapons),
+        ("jewelry", &file.jewelry),
+        ("lands", &file.lands),
+        ("map_leads_to", &file.map_leads_to),
+        ("garments", &file.garments),
+        ("instruments", &file.instruments),
+        ("books_about", &file.books_about),
+        ("magic_properties", &file.magic_properties),
+    ] {
+        if list.is_empty() {
+            return Err(blame_packs(GIFTS_FILE)(CcholError::Validation { table: GIFTS_FILE.into(), reason: format!("'{what}' is empty") }));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct GiftsFile {
+    weapons: Vec<String>,
+    jewelry: Vec<String>,
+    lands: Vec<String>,
+    map_leads_to: Vec<String>,
+    garments: Vec<String>,
+    instruments: Vec<String>,
+    books_about: Vec<String>,
+    magic_properties: Vec<String>,
+}
+
+/// The one a [GiftOrLegacy] is from.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub enum Giver {
+    Relative(Relation),
+    Other(OtherPeople),
+}
+
+impl Display for Giver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Relative(relation) => write!(f, "the {relation}"),
+            Self::Other(peep) => write!(f, "{peep}"),
+        }
+    }
+}
+
+/// Items given (or left behind) as [GiftOrLegacy].
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
+pub enum GiftItem {
+    Weapon { kind: String, materials: BTreeSet<Substance> },
+    Jewelry { kind: String, materials: BTreeSet<Substance>, color: Option<ExoticColor> },
+    DeedToLand { land: String },
+    MysteriousMap { leads_to: String },
+    Garment { kind: String, color: ExoticColor },
+    Instrument { kind: String, materials: BTreeSet<Substance> },
+    Book { about: String },
+    /// A key to an unknown lock.
+    Key { materials: BTreeSet<Substance> },
+    Statuette { materials: BTreeSet<Substance> },
+    /// A box that cannot be opened — not by any ordinary means, anyway.
+    LockedBox { materials: BTreeSet<Substance> },
+} impl GiftItem {
+    /// Generate a random [GiftItem].
+    fn random() -> (i32, Self) {
+        let roll = 1.d20();
+        let item = match roll {
+            ..=4 => Self::Weapon { kind: GIFTSFILE.weapons.random_of(), materials: Substance::random() },
+            ..=8 => Self::Jewelry {
+                kind: GIFTSFILE.jewelry.random_of(),
+                materials: Substance::random(),
+                color: if 1.d3() == 1 {Some(ExoticColor::random())} else {None}
+            },
+            9 => Self::DeedToLand { land: GIFTSFILE.lands.random_of() },
+            10 => Self::MysteriousMap { leads_to: GIFTSFILE.map_leads_to.random_of() },
+            ..=12 => Self::Garment { kind: GIFTSFILE.garments.random_of(), color: ExoticColor::random() },
+            ..=14 => Self::Instrument { kind: GIFTSFILE.instruments.random_of(), materials: Substance::random() },
+            15 => Self::Book { about: GIFTSFILE.books_about.random_of() },
+            16 => Self::Key { materials: Substance::random() },
+            ..=18 => Self::Statuette { materials: Substance::random() },
+            _ => Self::LockedBox { materials: Substance::random() }
+        };
+        (roll, item)
+    }
+}
+
+/// "iron", "iron and wood", "iron, wood and bronze".
+fn materials(materials: &BTreeSet<Substance>) -> String {
+    let names: Vec<String> = materials.iter().map(|m| m.to_string()).collect();
+    match names.as_slice() {
+        [] => "unknown material".into(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+impl Display for GiftItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Weapon { kind, materials: m } |
+            Self::Instrument { kind, materials: m } |
+            Self::Jewelry { kind, materials: m, color: None } => write!(f, "{kind} of {}", materials(m)),
+            Self::Jewelry { kind, materials: m, color: Some(c) } => write!(f, "{kind} of {}, {} in hue", materials(m), c.to_string().to_lowercase()),
+            Self::DeedToLand { land } => write!(f, "a deed to {land}"),
+            Self::MysteriousMap { leads_to } => write!(f, "a mysterious map leading to {leads_to}"),
+            Self::Garment { kind, color } => write!(f, "{kind}, {} in hue", color.to_string().to_lowercase()),
+            Self::Book { about } => write!(f, "a book of {about}"),
+            Self::Key { materials: m } => write!(f, "a key of {} to an unknown lock", materials(m)),
+            Self::Statuette { materials: m } => write!(f, "a statuette of {}", materials(m)),
+            Self::LockedBox { materials: m } => write!(f, "a box of {} that cannot be opened", materials(m)),
+        }
+    }
+}
+
+/// A gift — or a legacy, if left behind by someone (likely) no longer around.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GiftOrLegacy {
+    item: GiftItem,
+    /// Magical property, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    magic: Option<String>,
+    giver: Giver,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    legacy: bool,
+} impl GiftOrLegacy {
+    /// Get the [item][GiftItem] itself.
+    pub fn item(&self) -> &GiftItem {
+        &self.item
+    }
+
+    /// Get the magical property, if any.
+    pub fn magic(&self) -> Option<&str> {
+        self.magic.as_deref()
+    }
+
+    /// Who gave (or left behind) the item?
+    pub fn giver(&self) -> &Giver {
+        &self.giver
+    }
+
+    /// Is it a legacy rather than a gift?
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+}
+
+/// A random [Relation] old enough to give gifts to a newborn — no spouses or children, that is.
+fn elder_relation() -> Relation {
+    loop {
+        match Relation::random() {
+            Relation::Spouse |
+            Relation::Son | Relation::Daughter |
+            Relation::Descendant { .. } => continue,
+            relation => return relation
+        }
+    }
+}
+
+/// Generate a random [GiftOrLegacy] and add it into the possessions in `workpad`.
+pub fn random(workpad: &mut Workpad) -> GiftOrLegacy {
+    let (roll, item) = GiftItem::random();
+    roll_log::record("863", roll, &[], item.as_ref());
+    let gift = GiftOrLegacy {
+        item,
+        magic: if 1.d10() <= 2 {Some(GIFTSFILE.magic_properties.random_of())} else {None},
+        giver: if 1.d10() <= 6 {
+            Giver::Relative(elder_relation())
+        } else {
+            Giver::Other(OtherPeople::random(&*workpad))
+        },
+        legacy: 1.d2() == 1,
+    };
+    *workpad += gift.clone();
+    gift
+}
+
+impl Display for GiftOrLegacy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.item)?;
+        if let Some(magic) = &self.magic {
+            write!(f, " that {magic}")?;
+        }
+        write!(f, ", {} by {}", if self.legacy {"left"} else {"given"}, self.giver)
+    }
+}
+
+#[cfg(test)]
+mod gifts_n_legacies_tests {
+    use crate::{racial::Race, social::culture::Culture};
+
+    use super::*;
+
+    #[test]
+    fn gifts_file_data_integrity() {
+        try_load().unwrap();
+    }
+
+    #[test]
+    fn gifts_become_possessions() {
+        let mut workpad = Workpad::with_seed(863);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        let gifts: Vec<GiftOrLegacy> = (0..50).map(|_| random(&mut workpad)).collect();
+        assert_eq!(gifts.len(), workpad.get_possessions().len());
+        for gift in &gifts {
+            assert!(gift.to_string().contains(if gift.is_legacy() {", left by "} else {", given by "}));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! 873: Psionic Abilities
+use std::fmt::Display;
+
+use cchol_pm::HasRollRange;
+use lazy_static::lazy_static;
+use rpgassist::{ext::IsNamed, ranking::{Rank, rank::IsRanked}, stat::StatBase};
+use serde::{Deserialize, Serialize};
+
+use crate::{Workpad, data::{blame_packs, check, load_json, loaded}, dice::DiceExt, error::Result, roll_log, roll_range::*, serialize::{deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}};
+
+static PSIONIC_FILE: &'static str = "psionic.json";
+lazy_static! {
+    static ref PSIONICS_DATA: Result<Vec<PsionicNote>> = load_json(PSIONIC_FILE);
+    static ref PSIONICS: &'static Vec<PsionicNote> = loaded(&PSIONICS_DATA);
+
+    static ref PSIONICS_RANGE: RollRange = validate_cr_ranges("PSIONICS", &PSIONICS, None);
+}
+
+/// Load and validate [PsionicAbility] data.
+pub(crate) fn try_load() -> Result<()> {
+    let psionics = check(&PSIONICS_DATA)?;
+    try_validate_cr_ranges("PSIONICS", psionics, None).map_err(blame_packs(PSIONIC_FILE))?;
+    Ok(())
+}
+
+/// Psionic ability table entry.
+#[derive(Debug, Deserialize, Clone, HasRollRange)]
+struct PsionicNote {
+    name: String,
+    #[serde(deserialize_with = "deserialize_cr_range")]
+    _cr_range: RollRange,
+}
+
+/// Is the psionic ability under control, or does it manifest as it pleases?
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum PsionicControl {
+    Controlled,
+    Wild,
+}
+
+impl Display for PsionicControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Controlled => "controlled",
+            Self::Wild => "wild",
+        })
+    }
+}
+
+/// A psionic ability, e.g. telepathy.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PsionicAbility {
+    name: String,
+    rank: Rank,
+    control: PsionicControl,
+} impl PsionicAbility {
+    /// Generate a random [PsionicAbility].
+    ///
+    /// Magic potential rolled earlier (e.g. 39–41 at table 112) matters here: `Mag` in `workpad`
+    /// strengthens the ability, while without any (e.g. drained to zero) the ability is more likely wild.
+    pub fn random(workpad: &Workpad) -> Self {
+        let (roll, note) = PSIONICS.roll_in_range(&PSIONICS_RANGE);
+        roll_log::record("873", roll, &[], note.name());
+        let mag = workpad.get_statmap().get(StatBase::Mag).map_or(0, |m| m.value());
+        let control = if 1.d10() <= if mag > 0 {3} else {5} {PsionicControl::Wild} else {PsionicControl::Controlled};
+        Self {
+            name: note.name.clone(),
+            rank: Rank::from(1.d4() + mag / 2),
+            control,
+        }
+    }
+
+    /// Get the ability's [control][PsionicControl].
+    pub fn control(&self) -> PsionicControl {
+        self.control
+    }
+
+    /// Is the ability wild?
+    pub fn is_wild(&self) -> bool {
+        self.control == PsionicControl::Wild
+    }
+}
+
+impl IsNamed for PsionicNote {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IsNamed for PsionicAbility {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IsRanked for PsionicAbility {
+    fn rank(&self) -> Rank {
+        self.rank
+    }
+}
+
+impl Display for PsionicAbility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} (rank {})", self.control, self.name, self.rank)
+    }
+}
+
+#[cfg(test)]
+mod psionic_tests {
+    use rpgassist::stat::Stat;
+
+    use super::*;
+
+    #[test]
+    fn psionic_file_data_integrity() {
+        try_load().unwrap();
+    }
+
+    #[test]
+    fn mag_shapes_psionics() {
+        let mut workpad = Workpad::with_seed(873);
+        let psis: Vec<PsionicAbility> = (0..100).map(|_| PsionicAbility::random(&workpad)).collect();
+        assert!(psis.iter().all(|psi| psi.rank() >= 1 && psi.rank() <= 4));
+        assert!(psis.iter().any(|psi| psi.is_wild()) && psis.iter().any(|psi| !psi.is_wild()));
+
+        workpad += Stat::Mag { val: 6 };
+        assert!((0..100).all(|_| PsionicAbility::random(&workpad).rank() >= 4));
+    }
+}
@@ -0,0 +1,255 @@
+//! 528: Tragedies
+use std::fmt::Display;
+
+use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}};
+use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
+
+use crate::{Workpad, dice::{DiceExt, RandomOf, random_gender}, events::DeathSituation, roll_log, social::{birth_legitimacy::SiblingLegit, family::FamilyStructure}, traits::HasCulture};
+
+static FORTUNE_LOST_TO: [&str; 6] = [
+    "a failed venture",
+    "ruinous debts",
+    "thieves",
+    "a ruined harvest",
+    "gambling",
+    "a swindler"];
+static HOME_DESTROYED_BY: [&str; 5] = [
+    "fire",
+    "flood",
+    "raiders",
+    "war",
+    "an earthquake"];
+static TAKEN_AWAY_AS: [&str; 5] = [
+    "imprisoned",
+    "sold into slavery",
+    "pressed into military service",
+    "carried off by raiders",
+    "exiled"];
+
+/// Tragedies that befall a family — each one reshapes the family (or its wealth) to match.
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
+pub enum Tragedy {
+    /// Family fortune lost; `wealth` is what's left, if it could get any lower.
+    FortuneLost { cause: String, wealth: Option<String> },
+    /// Mother or father died.
+    ParentDied { parent: Gender, death: DeathSituation },
+    /// Both parents died.
+    ParentsDied { death: DeathSituation },
+    /// Mother or father was taken away, e.g. imprisoned.
+    ParentTakenAway { parent: Gender, fate: String },
+    SiblingDied { sibling: SiblingLegit, death: DeathSituation },
+    /// The family got scattered, and these siblings were lost in the process.
+    SiblingsScattered { siblings: Vec<SiblingLegit> },
+    HomeDestroyed { by: String, wealth: Option<String> },
+    /// The family was outlawed, losing wealth and (if any) nobility.
+    Outlawed { wealth: Option<String>, lost_nobility: bool },
+    /// Plague — which parents and siblings it took, if any.
+    Plague { parents: Vec<Gender>, siblings: Vec<SiblingLegit> },
+} impl Tragedy {
+    /// Generate a random [Tragedy] and let it reshape the [Parentage][crate::social::birth::Parentage]
+    /// and [SocialStatus][crate::social::status::SocialStatus] in `workpad` accordingly.
+    ///
+    /// Tragedies that would not apply (e.g. a sibling dying with no siblings around) get rerolled.
+    pub fn random(workpad: &mut Workpad) -> Self {
+        loop {
+            let roll = 1.d20();
+            if let Some(tragedy) = Self::befall(roll, workpad) {
+                roll_log::record("528", roll, &[], tragedy.as_ref());
+                return tragedy;
+            }
+        }
+    }
+
+    /// Let the tragedy of given `roll` befall the family in `workpad`, if it applies.
+    fn befall(roll: i32, workpad: &mut Workpad) -> Option<Self> {
+        let culture = workpad.culture();
+        let parentage = workpad.parentage_mut();
+        let (family, siblings) = match parentage {
+            Some(p) => (Some(p.family().clone()), p.siblings().len()),
+            None => (None, 0)
+        };
+        let has_parent = |parent: Gender| family.as_ref().is_some_and(|f| f.has_parent(parent));
+        // either parent, preferably a random one.
+        let some_parent = || {
+            let parent = random_gender();
+            let other = if parent == Gender::Male {Gender::Female} else {Gender::Male};
+            [parent, other].into_iter().find(|p| has_parent(*p))
+        };
+
+        let tragedy = match roll {
+            ..=3 => Self::FortuneLost { cause: FORTUNE_LOST_TO.random_of().into(), wealth: lose_wealth(workpad, 1) },
+            ..=6 => {
+                let parent = some_parent()?;
+                reshape_family(workpad, |f| f.without_parent(parent, culture));
+                Self::ParentDied { parent, death: DeathSituation::random() }
+            },
+            ..=8 => {
+                some_parent()?;
+                reshape_family(workpad, |f| f.orphaned(culture));
+                Self::ParentsDied { death: DeathSituation::random() }
+            },
+            ..=10 => {
+                let parent = some_parent()?;
+                reshape_family(workpad, |f| f.without_parent(parent, culture));
+                Self::ParentTakenAway { parent, fate: TAKEN_AWAY_AS.random_of().into() }
+            },
+            ..=12 if siblings > 0 => Self::SiblingDied { sibling: lose_siblings(workpad, 1).remove(0), death: DeathSituation::random() },
+            ..=14 if siblings > 0 => Self::SiblingsScattered { siblings: lose_siblings(workpad, 1.d(siblings)) },
+            ..=14 => return None,
+            ..=16 => Self::HomeDestroyed { by: HOME_DESTROYED_BY.random_of().into(), wealth: lose_wealth(workpad, 1) },
+            ..=18 => {
+                let wealth = lose_wealth(workpad, 2);
+                let lost_nobility = workpad.try_social_status().is_ok_and(|s| s.nobility().is_some());
+                if lost_nobility {
+                    let mut status = workpad.get_social_status().clone();
+                    status.demote_nobility();
+                    *workpad += status;
+                }
+                Self::Outlawed { wealth, lost_nobility }
+            },
+            _ => {
+                if family.is_none() || (siblings == 0 && some_parent().is_none()) {
+                    return None;
+                }
+                let parents: Vec<Gender> = [Gender::Female, Gender::Male].into_iter()
+                    .filter(|p| has_parent(*p) && 1.d2() == 1)
+                    .collect();
+                for parent in &parents {
+                    reshape_family(workpad, |f| f.without_parent(*parent, culture));
+                }
+                let died = (0..siblings).filter(|_| 1.d3() == 1).count();
+                Self::Plague { parents, siblings: lose_siblings(workpad, died) }
+            }
+        };
+        Some(tragedy)
+    }
+}
+
+/// Drop the family wealth by `levels`, giving the new wealth level if it did drop.
+fn lose_wealth(workpad: &mut Workpad, levels: usize) -> Option<String> {
+    let mut status = workpad.try_social_status().ok()?.clone();
+    if !status.lower_wealth(levels, workpad.culture()) {
+        return None;
+    }
+    let wealth = status.wealth().name().to_string();
+    *workpad += status;
+    Some(wealth)
+}
+
+/// Reshape the family in `workpad` with `reshape`, and drop the wealth to match the new family, if needed.
+fn reshape_family(workpad: &mut Workpad, reshape: impl FnOnce(&FamilyStructure) -> FamilyStructure) {
+    let Some(parentage) = workpad.parentage_mut() else { return };
+    let family = reshape(parentage.family());
+    let max_wealth = family.max_wealth();
+    *parentage.family_mut() = family;
+    if let (Some(max), Ok(status)) = (max_wealth, workpad.try_social_status()) {
+        let mut status = status.clone();
+        status.cap_wealth(max, workpad.culture());
+        *workpad += status;
+    }
+}
+
+/// Remove `count` random siblings from the family in `workpad`.
+fn lose_siblings(workpad: &mut Workpad, count: usize) -> Vec<SiblingLegit> {
+    let Some(parentage) = workpad.parentage_mut() else { return vec![] };
+    let siblings = parentage.siblings_mut();
+    (0..count.min(siblings.len()))
+        .map(|_| siblings.remove(1.d(siblings.len()) - 1))
+        .collect()
+}
+
+/// "the mother"/"the father".
+fn parent_name(parent: &Gender) -> &'static str {
+    if *parent == Gender::Male {"the father"} else {"the mother"}
+}
+
+/// "a brother", "an illegitimate sister", etc.
+fn sibling_name(sibling: &SiblingLegit) -> String {
+    let relation = if sibling.gender() == Gender::Male {"brother"} else {"sister"};
+    match sibling {
+        SiblingLegit::Legit(_) => format!("a {relation}"),
+        SiblingLegit::Illegit(_) => format!("an illegitimate {relation}"),
+    }
+}
+
+/// "1 sibling", "2 siblings".
+fn siblings_count(siblings: &[SiblingLegit]) -> String {
+    format!("{} sibling{}", siblings.len(), if siblings.len() == 1 {""} else {"s"})
+}
+
+impl Display for Tragedy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FortuneLost { cause, .. } => write!(f, "the family fortune was lost to {cause}"),
+            Self::ParentDied { parent, death } => write!(f, "{} died {death}", parent_name(parent)),
+            Self::ParentsDied { death } => write!(f, "both parents died {death}"),
+            Self::ParentTakenAway { parent, fate } => write!(f, "{} was {fate}", parent_name(parent)),
+            Self::SiblingDied { sibling, death } => write!(f, "{} died {death}", sibling_name(sibling)),
+            Self::SiblingsScattered { siblings } => write!(f, "the family was scattered, and {} lost", siblings_count(siblings)),
+            Self::HomeDestroyed { by, .. } => write!(f, "the family home was destroyed by {by}"),
+            Self::Outlawed { lost_nobility: false, .. } => write!(f, "the family was outlawed"),
+            Self::Outlawed { lost_nobility: true, .. } => write!(f, "the family was outlawed and stripped of its titles"),
+            Self::Plague { parents, siblings } => {
+                let mut taken: Vec<String> = parents.iter().map(|p| parent_name(p).to_string()).collect();
+                if !siblings.is_empty() {
+                    taken.push(siblings_count(siblings));
+                }
+                match taken.as_slice() {
+                    [] => write!(f, "plague swept through the family, yet spared them all"),
+                    [one] => write!(f, "plague swept through the family, taking {one}"),
+                    [rest @ .., last] => write!(f, "plague swept through the family, taking {} and {last}", rest.join(", ")),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tragedy_tests {
+    use crate::{racial::Race, social::{birth::Parentage, culture::Culture, status::SocialStatus}};
+
+    use super::*;
+
+    #[test]
+    fn tragedies_reshape_the_family() {
+        let mut workpad = Workpad::with_seed(528);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        workpad += SocialStatus::random(workpad.culture());
+        let parentage = Parentage::random(&mut workpad);
+        workpad += parentage;
+        for _ in 0..200 {
+            let mut pad = workpad.clone();
+            let siblings = pad.parentage_mut().unwrap().siblings().len();
+            let wealth = pad.get_social_status().wealth().clone();
+            let tragedy = Tragedy::random(&mut pad);
+            let parentage = pad.take_parentage();
+            match &tragedy {
+                Tragedy::ParentsDied { .. } => {
+                    assert!(!parentage.family().has_parent(Gender::Male));
+                    assert!(!parentage.family().has_parent(Gender::Female));
+                },
+                Tragedy::SiblingDied { .. } => assert_eq!(siblings - 1, parentage.siblings().len()),
+                Tragedy::SiblingsScattered { siblings: lost } |
+                Tragedy::Plague { siblings: lost, .. } => assert_eq!(siblings - lost.len(), parentage.siblings().len()),
+                Tragedy::FortuneLost { wealth: Some(_), .. } => assert!(wealth.is_above(pad.get_social_status().wealth().name())),
+                _ => ()
+            }
+            assert!(!tragedy.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn tragedies_without_family_still_happen() {
+        let mut workpad = Workpad::with_seed(528);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        for _ in 0..50 {
+            assert!(matches!(Tragedy::random(&mut workpad),
+                Tragedy::FortuneLost { wealth: None, .. } |
+                Tragedy::HomeDestroyed { wealth: None, .. } |
+                Tragedy::Outlawed { wealth: None, lost_nobility: false }));
+        }
+    }
+}
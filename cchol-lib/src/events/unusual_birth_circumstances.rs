@@ -1,10 +1,11 @@
-use std::{collections::HashSet, hash::Hash};
+use std::{collections::BTreeSet, hash::Hash};
 
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use rpgassist::{gender::Gender, stat::Stat};
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
 
-use crate::{StatMap, Workpad, body::Birthmark, racial::Race, social::{BiMod, culture::Culture}, traits::personality::DLNTrait};
+use crate::{StatMap, Workpad, body::{Birthmark, PhysicalAffliction}, dice::{DiceExt, random_gender}, events::{Blessing, Curse, DeathSituation, GiftOrLegacy, PsionicAbility, Tragedy, gifts_n_legacies}, modifier::BiMod, roll_log::{self, AppliedMod}, social::Deity, traits::personality::{self, DLNTrait, PersonalityTrait, TraitRollResult}};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum Ubc3941 {
@@ -15,39 +16,80 @@ pub enum Ubc3941 {
     E07,
     E0809,
     E10,
-} impl Hash for Ubc3941 {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+} impl Ubc3941 {
+    /// Variant ordinal — payload(s) don't count when comparing/hashing.
+    fn ordinal(&self) -> u8 {
         match self {
-            Self::E01 { .. } => 1.hash(state),
-            Self::E0203      => 2.hash(state),
-            Self::E0405      => 3.hash(state),
-            Self::E06 { .. } => 4.hash(state),
-            Self::E07        => 5.hash(state),
-            Self::E0809      => 6.hash(state),
-            Self::E10        => 7.hash(state),
+            Self::E01 { .. } => 1,
+            Self::E0203      => 2,
+            Self::E0405      => 3,
+            Self::E06 { .. } => 4,
+            Self::E07        => 5,
+            Self::E0809      => 6,
+            Self::E10        => 7,
         }
     }
+} impl Hash for Ubc3941 {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.ordinal().hash(state)
+    }
 } impl PartialEq for Ubc3941 {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::E01 { .. }, Self::E01 { .. })|
-            (Self::E0203, Self::E0203)          |
-            (Self::E0405, Self::E0405)          |
-            (Self::E06 { .. }, Self::E06 { .. })|
-            (Self::E07, Self::E07)              |
-            (Self::E0809, Self::E0809)          |
-            (Self::E10, Self::E10)             => true,
-            _ => false
-        }
+        self.ordinal() == other.ordinal()
     }
 } impl Eq for Ubc3941 {}
 
+impl PartialOrd for Ubc3941 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+} impl Ord for Ubc3941 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.ordinal().cmp(&other.ordinal())
+    }
+}
+
+/// Roll 1d3 distinct stats to change by 1d3 each, in direction of `sign`, and apply the changes into `workpad`.
+fn stat_changes(workpad: &mut Workpad, sign: i32) -> StatMap {
+    let mut changes = StatMap::zeroed();
+    let mut count = 1.d3();
+    while count > 0 {
+        let val = sign * 1.d3();
+        let stat = match 1.d8() {
+            ..=1 => Stat::Str { val },
+            2 => Stat::Dex { val },
+            3 => Stat::Con { val },
+            4 => Stat::Int { val },
+            5 => Stat::App { val },
+            6 => Stat::Cha { val },
+            7 => Stat::Will { val },
+            _ => Stat::Mag { val }
+        };
+        if changes.get(stat.stat_base()).is_some_and(|s| s.value() != 0) {
+            continue;
+        }
+        count -= 1;
+        // `set`, not `+=` — the latter would clamp negative changes at zero (or one).
+        changes.set(stat.clone()).expect("INTERNAL ERROR: zeroed StatMap should accept all stats!");
+        *workpad += stat;
+    }
+    changes
+}
+
+/// A darkside [trait][DLNTrait] of one's own.
+fn darkside_trait() -> DLNTrait {
+    match personality::random_darkside(&vec![]) {
+        TraitRollResult::Add(PersonalityTrait::DLN(dln)) => dln,
+        _ => unreachable!("Darkside trait with no bans should always be a plain DLN addition…")
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum Ubc4950 {
     E01, E02, E03
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
 pub enum UnusualBirthCircumstance {
     UbcNe05,
     Ubc0610,
@@ -59,8 +101,8 @@ pub enum UnusualBirthCircumstance {
     Ubc3234,
     Ubc3537,
     Ubc38nn,
-    Ubc3941(HashSet<Ubc3941>),
-    Ubc4244(HashSet<Ubc3941>),
+    Ubc3941(BTreeSet<Ubc3941>),
+    Ubc4244(BTreeSet<Ubc3941>),
     Ubc4548 { curse: Curse },
     Ubc4950(Ubc4950),
     Ubc5153,
@@ -95,8 +137,11 @@ pub enum UnusualBirthCircumstance {
     }
 } impl UnusualBirthCircumstance {
     pub fn random(workpad: &mut Workpad) -> Self {
-        let roll = 1.d100() + workpad.bimod();
-        match roll {
+        let boost = workpad.bimod_boost();
+        let bimod = workpad.bimod();
+        let raw = 1.d100();
+        let roll = raw + bimod;
+        let ubc = match roll {
             ..=5 => Self::UbcNe05,
             ..=10 => Self::Ubc0610,
             ..=20 => Self::Ubc1120,
@@ -108,7 +153,7 @@ pub enum UnusualBirthCircumstance {
             ..=37 => Self::Ubc3537,
             38 => Self::Ubc38nn,
             n if roll <= 44 => {
-                let mut ubc3941s = HashSet::new();
+                let mut ubc3941s = BTreeSet::new();
                 let mut c = 1.d3();
                 while c > 0 && ubc3941s.len() < 7 {
                     c -= 1;
@@ -127,6 +172,12 @@ pub enum UnusualBirthCircumstance {
                     }
                     ubc3941s.insert(variant);
                 }
+                // magic potential changes stick, e.g. for psionics (873) to draw upon.
+                for ubc in &ubc3941s {
+                    if let Ubc3941::E01 { stat } | Ubc3941::E06 { stat } = ubc {
+                        *workpad += stat.clone();
+                    }
+                }
                 
                 if n < 42 {Self::Ubc3941(ubc3941s)} else {Self::Ubc4244(ubc3941s)}
             },
@@ -145,7 +196,7 @@ pub enum UnusualBirthCircumstance {
             ..=69 => Self::Ubc6569 { birthmark: Birthmark::random() },
             ..=75 => Self::Ubc7075 { curse: Curse::random(workpad) },
             ..=81 => Self::Ubc7681 { blessing: Blessing::random(workpad) },
-            ..=85 => Self::Ubc8285 { gender: Gender::random() },
+            ..=85 => Self::Ubc8285 { gender: random_gender() },
             86 => Self::Ubc86nn,
             ..=88 => Self::Ubc8788 { prophesy: DeathSituation::random() },
             ..=93 => Self::Ubc8993 { affliction: PhysicalAffliction::random(workpad) },
@@ -160,11 +211,91 @@ pub enum UnusualBirthCircumstance {
             },
             ..=105 => Self::Ubc101105,
             ..=110 => Self::Ubc106110 {
-                stats: (),
-                affliction: (),
-                curse: (),
-                dln: ()
+                stats: stat_changes(workpad, -1),
+                affliction: PhysicalAffliction::random(workpad),
+                curse: Curse::random(workpad),
+                dln: darkside_trait(),
+            },
+            _ => {
+                let stats = stat_changes(workpad, 1);
+                let affliction = PhysicalAffliction::random(workpad);
+                let gift = gifts_n_legacies::random(workpad);
+                // the patron deity has a say in the blessing, if it's a deity's to give.
+                let deity = Deity::random(workpad);
+                let blessing = Blessing::random_from(&deity, workpad);
+                Self::Ubc111xxx { stats, affliction, gift, blessing, deity }
+            }
+        };
+        let mut mods = vec![AppliedMod::BiMod(bimod - boost)];
+        if boost != 0 {
+            mods.push(AppliedMod::BiModBoost(boost));
+        }
+        roll_log::record("112", raw, &mods, ubc.as_ref());
+        ubc
+    }
+}
+#[cfg(test)]
+mod ubc_tests {
+    use rpgassist::ext::IsNamed;
+
+    use crate::{places::birthplace::PlaceOfBirth, racial::Race, social::{birth::Parentage, culture::Culture, status::SocialStatus}, traits::HasCulture};
+
+    use super::*;
+
+    #[test]
+    fn stat_changes_apply_to_workpad() {
+        let mut workpad = Workpad::with_seed(112);
+        for sign in [1, -1] {
+            let before = workpad.get_statmap().clone();
+            let changes = stat_changes(&mut workpad, sign);
+            let changed: Vec<&Stat> = changes.iter().filter(|s| s.value() != 0).collect();
+            assert!((1..=3).contains(&changed.len()));
+            for stat in changed {
+                assert_eq!(sign, stat.value().signum());
+                let mut expected = before.get(stat.stat_base()).unwrap().clone();
+                expected += stat.clone();
+                assert_eq!(expected.value(), workpad.get_statmap().get(stat.stat_base()).unwrap().value());
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn high_end_rolls_have_outcomes() {
+        let mut workpad = Workpad::with_seed(112);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        workpad += SocialStatus::random(workpad.culture());
+        let parentage = Parentage::random(&mut workpad);
+        workpad += parentage;
+        let place = PlaceOfBirth::random(&mut workpad);
+        workpad += place;
+        for _ in 0..3 {
+            workpad.boost_bimod(200);
+            assert!(matches!(UnusualBirthCircumstance::random(&mut workpad), UnusualBirthCircumstance::Ubc111xxx { .. }));
+        }
+    }
+
+    #[test]
+    fn patron_deity_gives_the_blessing() {
+        let mut workpad = Workpad::with_seed(111);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        workpad += SocialStatus::random(workpad.culture());
+        let parentage = Parentage::random(&mut workpad);
+        workpad += parentage;
+        let place = PlaceOfBirth::random(&mut workpad);
+        workpad += place;
+        let mut graced = 0;
+        for _ in 0..50 {
+            workpad.boost_bimod(200);
+            let UnusualBirthCircumstance::Ubc111xxx { blessing, deity, .. } = UnusualBirthCircumstance::random(&mut workpad) else {
+                panic!("Not a 111+ circumstance!")
+            };
+            if let Some(by) = blessing.deity() {
+                assert_eq!(deity.name(), by.name());
+                graced += 1;
+            }
+        }
+        assert!(graced > 0);
+    }
+}
@@ -1,5 +1,10 @@
 pub mod animal;
+pub mod biography;
 pub mod body;
+pub mod data;
+pub mod dice;
+pub mod error;
+pub use error::CcholError;
 pub mod events;
 pub(crate) mod ext;
 pub mod misc;
@@ -7,11 +12,14 @@ pub mod modifier;
 pub mod pc;
 pub mod places;
 pub mod racial;
+pub mod roll_log;
 pub mod roll_range;
+pub mod save;
 pub(crate) mod serialize;
 pub mod skill;
 pub mod social;
 mod stat;
+pub mod stepwise;
 use rpgassist::gender::Gender;
 pub use stat::StatMap;
 mod traits;
@@ -19,20 +27,121 @@ pub(crate) mod string_manip;
 mod workpad;
 pub(crate) use workpad::Workpad;
 
-use crate::{racial::Race, social::{birth::Birth, culture::Culture, status::SocialStatus}, traits::HasCulture};
-
-pub fn generate_player_background(
-    name: &str,
-    gender: Option<&str>,
-    race: Option<&str>,
-    culture: Option<&str>,
-) {
-    let mut workpad = Workpad::new();
-    workpad.set_name(name);
-    workpad += Race::from(race);
-    workpad += workpad.race().adjust_gender(Gender::from(gender));
-    workpad += workpad.race().shift_culture_if_needed(Culture::from(culture));
+use crate::{dice::random_gender, error::Result, misc::Occupations, pc::PlayerCharacter, racial::Race, social::{birth::Birth, culture::Culture, status::SocialStatus}, traits::HasCulture};
+
+/// Options for [generate_player_background].
+/// 
+/// Anything left as `None` gets randomized.
+#[derive(Debug, Clone)]
+pub struct GenerationOptions {
+    pub name: String,
+    pub gender: Option<String>,
+    pub race: Option<String>,
+    pub culture: Option<String>,
+    pub seed: Option<u64>,
+    /// Keep the [roll log][roll_log::RollLog] in the finished character?
+    pub roll_log: bool,
+} impl GenerationOptions {
+    /// New set of options for character called `name`, everything else randomized.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            gender: None,
+            race: None,
+            culture: None,
+            seed: None,
+            roll_log: false,
+        }
+    }
+
+    /// Force specific [`gender`][Gender].
+    /// 
+    /// Chainable in any order.
+    pub fn with_gender(&mut self, gender: &str) -> &mut Self {
+        self.gender = Some(gender.into());
+        self
+    }
+
+    /// Force specific [`race`][Race].
+    /// 
+    /// Chainable in any order.
+    pub fn with_race(&mut self, race: &str) -> &mut Self {
+        self.race = Some(race.into());
+        self
+    }
+
+    /// Force specific [`culture`][Culture].
+    /// 
+    /// Chainable in any order.
+    pub fn with_culture(&mut self, culture: &str) -> &mut Self {
+        self.culture = Some(culture.into());
+        self
+    }
+
+    /// Force specific dice `seed` — same seed and options give the same character.
+    /// 
+    /// Chainable in any order.
+    pub fn with_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Keep the [roll log][roll_log::RollLog] — every table lookup made — in the character.
+    /// 
+    /// Chainable in any order.
+    pub fn with_roll_log(&mut self) -> &mut Self {
+        self.roll_log = true;
+        self
+    }
+}
+
+/// Generate a complete [PlayerCharacter] background in one go.
+/// 
+/// **FYI:** we *intentionally* panic if forced race/culture/gender are unknown.
+pub fn generate_player_background(options: &GenerationOptions) -> PlayerCharacter {
+    let mut workpad = match options.seed {
+        Some(seed) => Workpad::with_seed(seed),
+        None => Workpad::new()
+    };
+    workpad.set_name(&options.name);
+    workpad += Race::from(options.race.as_deref());
+    workpad += workpad.race().adjust_gender(options.gender.as_deref().map_or_else(random_gender, Gender::from));
+    workpad += workpad.race().shift_culture_if_needed(Culture::from(options.culture.as_deref()));
     workpad += SocialStatus::random(workpad.culture());
     let birth = Birth::random(&mut workpad);
     workpad += birth;
-}
\ No newline at end of file
+    let occupations = Occupations::random(&mut workpad);
+    workpad += occupations;
+    let mut pc = PlayerCharacter::create(&mut workpad);
+    if !options.roll_log {
+        pc.forget_roll_log();
+    }
+    pc
+}
+
+/// Generate a complete [PlayerCharacter] background in one go, non-panicky edition.
+/// 
+/// Data files get [loaded and validated][data::load_data] first, and forced
+/// race/culture/gender checked for existence.
+pub fn try_generate_player_background(options: &GenerationOptions) -> Result<PlayerCharacter> {
+    data::load_data()?;
+    if let Some(race) = &options.race {
+        Race::try_from_name(race)?;
+    }
+    if let Some(culture) = &options.culture {
+        Culture::try_from_name(culture)?;
+    }
+    if let Some(gender) = &options.gender {
+        try_gender(gender)?;
+    }
+    Ok(generate_player_background(options))
+}
+
+/// Check that `gender` is something [Gender::from] comprehends.
+pub(crate) fn try_gender(gender: &str) -> Result<Gender> {
+    match gender.to_lowercase().as_str() {
+        "m"|"male"|"mies" => Ok(Gender::Male),
+        "f"|"n"|"female"|"nainen"|"t"|"tyttö"|"tytto" => Ok(Gender::Female),
+        _ => Err(CcholError::UnknownName { kind: "gender", name: gender.into() })
+    }
+}
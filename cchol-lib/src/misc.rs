@@ -2,7 +2,7 @@ mod color;
 pub use color::ExoticColor;
 pub mod defaults;
 mod occupation;
-pub use occupation::OccupationPerformance;
+pub use occupation::{Occupation, OccupationAchievementLevel, OccupationKind, OccupationPerformance, Occupations, WorkAttitude};
 mod serious_wound;
 pub use serious_wound::SeriousWound;
 pub mod datum;
@@ -11,7 +11,14 @@ pub use shape::Shape;
 mod material;
 pub use material::Substance;
 
-use dicebag::DiceExt;
+use crate::{dice::DiceExt, error::Result};
+
+/// Load and validate all the miscellaneous data.
+pub(crate) fn try_load() -> Result<()> {
+    color::try_load()?;
+    occupation::try_load()?;
+    shape::try_load()
+}
 
 pub trait ConditionalExec {
     fn if_p<F, T>(&self, f: F) -> Option<T>
@@ -1,13 +1,15 @@
 //! 865: Color
 //! 
 //! Some colors are exotic in some contexts…
-use std::{fmt::Display, fs};
+use std::fmt::Display;
 
-use dicebag::{DiceExt, IsOne, RandomOf};
+use dicebag::IsOne;
 use lazy_static::lazy_static;
 use rpgassist::resolve::resolve_in_place::ResolveInPlace;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::{data::{check, load_json, loaded}, dice::{DiceExt, RandomOf}, error::{CcholError, Result}};
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum ColorTint {
     Pastel,
@@ -83,26 +85,33 @@ pub struct ExoticColor {
     exotic: bool,
 }
 
-trait ColorValidator{fn validate_colors(self)->Vec<ExoticColor>;}
+trait ColorValidator{fn validate_colors(self)->Result<Vec<ExoticColor>>;}
 impl ColorValidator for Vec<ExoticColor> {
-    fn validate_colors(self)->Vec<ExoticColor> {
+    fn validate_colors(self)->Result<Vec<ExoticColor>> {
         if self.iter()
             .filter(|c|!c.exotic)
             .collect::<Vec<&ExoticColor>>()
             .is_empty() {
-                panic!("No mundane colors defined in '{COLOR_FILE}'!")
+                return Err(CcholError::Validation {
+                    table: COLOR_FILE.into(),
+                    reason: "No mundane colors defined!".into()
+                })
             }
-        self
+        Ok(self)
     }
 }
 
-static COLOR_FILE: &'static str = "./data/color.json";
+static COLOR_FILE: &'static str = "color.json";
 lazy_static! {
-    static ref COLORS: Vec<ExoticColor> = serde_jsonc::from_str::<Vec<ExoticColor>>(
-        &fs::read_to_string(COLOR_FILE).expect(format!("Error with '{COLOR_FILE}'?!").as_str())
-    )
-    .expect("JSON error")
-    .validate_colors();
+    static ref COLORS_DATA: Result<Vec<ExoticColor>> = load_json::<Vec<ExoticColor>>(COLOR_FILE)
+        .and_then(ColorValidator::validate_colors);
+    static ref COLORS: &'static Vec<ExoticColor> = loaded(&COLORS_DATA);
+}
+
+/// Load and validate [ExoticColor] data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&COLORS_DATA)?;
+    Ok(())
 }
 
 impl ResolveInPlace for ExoticColor {
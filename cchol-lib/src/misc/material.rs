@@ -1,21 +1,22 @@
-use std::collections::HashSet;
+use std::{collections::BTreeSet, fmt::Display};
 
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 
+use crate::dice::DiceExt;
+
 lazy_static! {
     static ref SUBSTANCE_COUNT: usize = Substance::iter().count();
 }
 /// Various substances. Some more exotic/unusual than others…
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
 pub enum Substance {
     Granite, Marble, Wood, IronWood, PreciousMetal,
     Cloth, Gemstone, Iron, Bronze
 } impl Substance {
-    pub fn random() -> HashSet<Self> {
-        let mut set = HashSet::new();
+    pub fn random() -> BTreeSet<Self> {
+        let mut set = BTreeSet::new();
         let mut i = 1;
         // loop around as long as we have to and we've no exhausted ALL options...
         while i > 0 && set.len() < *SUBSTANCE_COUNT {
@@ -37,4 +38,19 @@ pub enum Substance {
         }
         set
     }
-}
\ No newline at end of file
+}
+impl Display for Substance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Granite => "granite",
+            Self::Marble => "marble",
+            Self::Wood => "wood",
+            Self::IronWood => "ironwood",
+            Self::PreciousMetal => "precious metal",
+            Self::Cloth => "cloth",
+            Self::Gemstone => "gemstone",
+            Self::Iron => "iron",
+            Self::Bronze => "bronze",
+        })
+    }
+}
@@ -1,11 +1,12 @@
-use std::{fmt::Display, fs};
+//! 420–423: Occupations
+use std::fmt::Display;
 
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
-use rpgassist::{ranking::{Rank, rank::IsRanked}, ext::IsNamed};
+use rpgassist::{ranking::{Rank, rank::IsRanked}, ext::IsNamed, gender::Gender, serialize::serial_strings::deserialize_strings_to_vec};
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
 
-use crate::{misc::datum::is_april_fools, traits::personality::{self, AffectsAlignment, Alignment}};
+use crate::{Workpad, data::{blame_packs, check, load_json, loaded}, dice::DiceExt, error::{CcholError, Result}, misc::datum::is_april_fools, roll_log, skill::SkillBase, social::{culture::CultureCoreType, family::FamilyStructure, people::guardian::Guardian, status::SocialStatus, wealth::Wealth}, traits::{HasCulture, HasCultureCoreType, personality::{self, AffectsAlignment, Alignment}}};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OccupationAchievementLevel {
@@ -29,6 +30,25 @@ impl IsRanked for OccupationAchievementLevel {
 }
 
 impl OccupationAchievementLevel {
+    /// Skill rank as a plain number.
+    fn skill_rank(&self) -> i32 {
+        match self {
+            Self::Apprentice { skill_rank }     |
+            Self::Journeyman { skill_rank }     |
+            Self::MasterCraftsman { skill_rank }|
+            Self::MasterOfNote { skill_rank }   |
+            Self::SkilledTradesman { skill_rank } => *skill_rank as i32
+        }
+    }
+
+    /// Generate random [OccupationAchievementLevel] for someone just starting out — an apprentice or a journeyman.
+    pub fn random_starting() -> Self {
+        match 1.d20() {
+            ..=12 => Self::Apprentice { skill_rank: 1.d2() },
+            _ => Self::Journeyman { skill_rank: 1.d3() + 2 }
+        }
+    }
+
     pub fn random() -> Self {
         match 1.d20() {
             ..=2 => Self::Apprentice { skill_rank: 1.d2() },
@@ -56,14 +76,152 @@ impl Display for OccupationAchievementLevel {
     }
 }
 
-static WORK_ATTITUDES_FILE: &'static str = "./data/work_attitude.json";
+static WORK_ATTITUDES_FILE: &'static str = "work_attitude.json";
 lazy_static! {
-    static ref WORK_ATTITUDES: Vec<WorkAttitude> = serde_jsonc::from_str(
-            &fs::read_to_string(WORK_ATTITUDES_FILE).expect(format!("Error with '{WORK_ATTITUDES_FILE}'?!").as_str())
-        ).expect("JSON error");
+    static ref WORK_ATTITUDES_DATA: Result<Vec<WorkAttitude>> = load_json(WORK_ATTITUDES_FILE);
+    static ref WORK_ATTITUDES: &'static Vec<WorkAttitude> = loaded(&WORK_ATTITUDES_DATA);
     static ref WORK_ATTITUDES_COUNT: usize = WORK_ATTITUDES.len();
 }
 
+static OCCUPATIONS_FILE: &'static str = "occupation.json";
+lazy_static! {
+    static ref OCCUPATIONS_DATA: Result<Vec<OccupationNote>> = load_json(OCCUPATIONS_FILE);
+    static ref OCCUPATIONS: &'static Vec<OccupationNote> = loaded(&OCCUPATIONS_DATA);
+}
+
+/// Load [WorkAttitude] and [Occupation] data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&WORK_ATTITUDES_DATA)?;
+    let occupations = check(&OCCUPATIONS_DATA)?;
+    for skill in occupations.iter().flat_map(|o| &o.skills) {
+        SkillBase::try_from_name(skill).map_err(blame_packs(OCCUPATIONS_FILE))?;
+    }
+    for wealth in occupations.iter().flat_map(|o| o.min_wealth.iter().chain(&o.max_wealth)) {
+        Wealth::try_from_name(wealth).map_err(blame_packs(OCCUPATIONS_FILE))?;
+    }
+    for core_type in [CultureCoreType::Primitive, CultureCoreType::Nomad, CultureCoreType::Barbarian, CultureCoreType::Civilized, CultureCoreType::Decadent] {
+        if !occupations.iter().any(|o| o.fits(&core_type)) {
+            return Err(blame_packs(OCCUPATIONS_FILE)(CcholError::Validation {
+                table: OCCUPATIONS_FILE.into(),
+                reason: format!("No occupations for '{core_type}'")
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// Kinds of [Occupation].
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, AsRefStr)]
+pub enum OccupationKind {
+    /// Hunters, fishers, gatherers.
+    Gatherer,
+    Herder,
+    Farmer,
+    Craft,
+    Merchant,
+    Criminal,
+    Military,
+    Religious,
+    Entertainer,
+    Scholar,
+    Laborer,
+    Service,
+    Seafaring,
+}
+
+/// Occupation table entry.
+#[derive(Debug, Deserialize, Clone)]
+struct OccupationNote {
+    name: String,
+    #[serde(deserialize_with = "deserialize_strings_to_vec")]
+    culture: Vec<String>,
+    kind: OccupationKind,
+    #[serde(default)]
+    skills: Vec<String>,
+    #[serde(default)]
+    min_wealth: Option<String>,
+    #[serde(default)]
+    max_wealth: Option<String>,
+} impl OccupationNote {
+    /// See if the occupation is found in the given `culture`.
+    fn fits(&self, culture: &impl HasCultureCoreType) -> bool {
+        self.culture.iter().any(|c| c == culture.core_type().as_str())
+    }
+
+    /// See if the occupation is within reach at the given [Wealth] level.
+    fn fits_wealth(&self, wealth: &Wealth) -> bool {
+        !self.min_wealth.as_ref().is_some_and(|min| wealth.is_below(min)) &&
+        !self.max_wealth.as_ref().is_some_and(|max| wealth.is_above(max))
+    }
+}
+
+/// An occupation, e.g. blacksmith.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Occupation {
+    name: String,
+    kind: OccupationKind,
+    /// Skills related to the occupation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skills: Vec<String>,
+} impl Occupation {
+    /// Generate a random culture-appropriate [Occupation].
+    pub fn random(culture: &impl HasCultureCoreType) -> Self {
+        Self::random_among(OCCUPATIONS.iter().filter(|o| o.fits(culture)).collect(), culture)
+    }
+
+    /// Generate a random [Occupation] appropriate for both the `culture` and the [Wealth] in `status`.
+    pub fn random_for_status(culture: &impl HasCultureCoreType, status: &SocialStatus) -> Self {
+        let notes: Vec<&OccupationNote> = OCCUPATIONS.iter()
+            .filter(|o| o.fits(culture) && o.fits_wealth(status.wealth()))
+            .collect();
+        // data packs might leave some wealth level without any, so…
+        if notes.is_empty() {
+            return Self::random(culture)
+        }
+        Self::random_among(notes, culture)
+    }
+
+    fn random_among(notes: Vec<&OccupationNote>, culture: &impl HasCultureCoreType) -> Self {
+        let roll = 1.d(notes.len());
+        let note = notes[roll - 1];
+        roll_log::record(match culture.core_type() {
+            CultureCoreType::Primitive => "420",
+            CultureCoreType::Nomad => "421",
+            CultureCoreType::Barbarian => "422",
+            _ => "423"
+        }, roll as i32, &[], &note.name);
+        Self {
+            name: note.name.clone(),
+            kind: note.kind,
+            skills: note.skills.clone(),
+        }
+    }
+
+    /// Get the [kind][OccupationKind] of occupation.
+    pub fn kind(&self) -> OccupationKind {
+        self.kind
+    }
+
+    /// Get the skills related to the occupation.
+    pub fn skills(&self) -> Vec<&'static SkillBase> {
+        self.skills.iter()
+            .map(|s| SkillBase::try_from_name(s).unwrap_or_else(|e| panic!("{e}")))
+            .collect()
+    }
+}
+
+impl IsNamed for Occupation {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for Occupation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WorkAttitude {
     l: String,
@@ -113,6 +271,7 @@ impl AffectsAlignment for WorkAttitude {
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct  OccupationPerformance {
+    occupation: Occupation,
     attitude: WorkAttitude,
     oa_lvl: OccupationAchievementLevel,
 }
@@ -124,8 +283,108 @@ impl IsRanked for OccupationPerformance {
 }
 
 impl OccupationPerformance {
-    fn random() -> Self {
-        Self { attitude: WorkAttitude::random(), oa_lvl: OccupationAchievementLevel::random() }
+    /// Generate a random culture-appropriate [OccupationPerformance].
+    pub fn random(culture: &impl HasCultureCoreType) -> Self {
+        Self { occupation: Occupation::random(culture), attitude: WorkAttitude::random(), oa_lvl: OccupationAchievementLevel::random() }
+    }
+
+    /// Generate a random [OccupationPerformance] appropriate for both the `culture` and the `status`.
+    pub fn random_for_status(culture: &impl HasCultureCoreType, status: &SocialStatus) -> Self {
+        Self { occupation: Occupation::random_for_status(culture, status), attitude: WorkAttitude::random(), oa_lvl: OccupationAchievementLevel::random() }
+    }
+
+    /// Get the [Occupation] itself.
+    pub fn occupation(&self) -> &Occupation {
+        &self.occupation
+    }
+
+    /// Get the [WorkAttitude].
+    pub fn attitude(&self) -> &WorkAttitude {
+        &self.attitude
+    }
+
+    /// Get the [OccupationAchievementLevel].
+    pub fn achievement(&self) -> &OccupationAchievementLevel {
+        &self.oa_lvl
+    }
+}
+
+impl Display for OccupationPerformance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.occupation, self.oa_lvl)
+    }
+}
+
+/// Who does what for a living — the parents (or whoever heads the household) and the character.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct Occupations {
+    /// Head of household first, then the other parent, if working too.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    parents: Vec<OccupationPerformance>,
+    /// The trade the character learned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    own: Option<OccupationPerformance>,
+} impl Occupations {
+    /// Generate random occupations for the household and the character, and grant the
+    /// related skills into `workpad` — the character's own at the achieved rank, the
+    /// head of household's at rank 1 (picked up while growing up).
+    pub fn random(workpad: &mut Workpad) -> Self {
+        let culture = workpad.culture();
+        let status = workpad.get_social_status().clone();
+        let family = workpad.get_birth().family().clone();
+        let mut parents = vec![];
+        if has_household(&family) {
+            parents.push(OccupationPerformance::random_for_status(culture, &status));
+            if family.has_parent(Gender::Male) && family.has_parent(Gender::Female) && 1.d3() == 1 {
+                parents.push(OccupationPerformance::random_for_status(culture, &status));
+            }
+        }
+        // follow in the footsteps of the head of household, or learn something else?
+        let occupation = match parents.first() {
+            Some(head) if 1.d2() == 1 => head.occupation.clone(),
+            _ => Occupation::random_for_status(culture, &status)
+        };
+        let own = OccupationPerformance {
+            occupation,
+            attitude: WorkAttitude::random(),
+            oa_lvl: OccupationAchievementLevel::random_starting(),
+        };
+        if let Some(head) = parents.first() {
+            for skill in head.occupation.skills() {
+                *workpad += (skill, 1);
+            }
+        }
+        for skill in own.occupation.skills() {
+            *workpad += (skill, own.oa_lvl.skill_rank());
+        }
+        Self { parents, own: Some(own) }
+    }
+
+    /// Get the parents' (or head of household's) occupations, if any.
+    pub fn parents(&self) -> &[OccupationPerformance] {
+        &self.parents
+    }
+
+    /// Get the character's own occupation, if any.
+    pub fn own(&self) -> Option<&OccupationPerformance> {
+        self.own.as_ref()
+    }
+
+    /// See if there's no occupations whatsoever.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty() && self.own.is_none()
+    }
+}
+
+/// See if the `family` has a household with someone earning its living.
+fn has_household(family: &FamilyStructure) -> bool {
+    match family {
+        FamilyStructure::Orphanage |
+        FamilyStructure::StreetKid { .. } |
+        FamilyStructure::Guardian(Guardian::Orphanage | Guardian::Institution(_) | Guardian::Unusual(_)) => false,
+        FamilyStructure::Adopted(family) |
+        FamilyStructure::Guardian(Guardian::Adopted(family)) => has_household(family),
+        _ => true
     }
 }
 
@@ -137,4 +396,55 @@ mod work_attitude_tests {
     fn work_attitude_data_integrity() {
         assert!(19 <= *WORK_ATTITUDES_COUNT);
     }
+}
+
+#[cfg(test)]
+mod occupation_tests {
+    use crate::{GenerationOptions, generate_player_background, social::culture::Culture};
+
+    use super::*;
+
+    #[test]
+    fn occupation_file_data_integrity() {
+        try_load().unwrap();
+    }
+
+    #[test]
+    fn occupations_fit_culture() {
+        for core_type in [CultureCoreType::Primitive, CultureCoreType::Nomad, CultureCoreType::Barbarian, CultureCoreType::Civilized, CultureCoreType::Decadent] {
+            for _ in 0..50 {
+                let occupation = Occupation::random(&core_type);
+                let note = OCCUPATIONS.iter().find(|o| o.name == occupation.name()).unwrap();
+                assert!(note.fits(&core_type));
+                assert_eq!(note.skills.len(), occupation.skills().len());
+            }
+        }
+    }
+
+    #[test]
+    fn occupations_fit_wealth() {
+        let culture = Culture::try_from_name("Civilized").unwrap();
+        for wealth in ["Destitute", "Poor", "Wealthy"] {
+            let status = SocialStatus::from_wealth(Wealth::try_from_name(wealth).unwrap());
+            for _ in 0..50 {
+                let occupation = Occupation::random_for_status(culture, &status);
+                let note = OCCUPATIONS.iter().find(|o| o.name == occupation.name()).unwrap();
+                assert!(note.fits_wealth(status.wealth()), "{} while {wealth}", occupation.name());
+            }
+        }
+    }
+
+    #[test]
+    fn own_occupation_grants_skills() {
+        for seed in 0..20 {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed);
+            let pc = generate_player_background(&options);
+            let own = pc.occupations().own().unwrap();
+            for skill in own.occupation().skills() {
+                let known = pc.skills().iter().find(|s| s.name() == skill.name()).unwrap();
+                assert!(known.rank() >= own.achievement().rank());
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -1,11 +1,11 @@
 //! 870: Serious Wounds
-use std::{collections::{HashMap, VecDeque}, fmt::Display};
+use std::{collections::{BTreeMap, VecDeque}, fmt::Display};
 
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use rpgassist::{body::location::BodyLocation, direction::bilateral::Bilateral, stat::Stat};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{ext::IsExplained, traits::personality::{PersonalityTrait, TraitVec, exotic_trait, mental_affliction}};
+use crate::{body::PhysicalAffliction, dice::{DiceExt, random_body_location, random_lr}, ext::IsExplained, traits::personality::{PersonalityTrait, TraitVec, exotic_trait, mental_affliction}};
 
 fn deserialize_bdt_maff<'de, D>(deserializer: D) -> Result<Vec<PersonalityTrait>, D::Error>
 where D: Deserializer<'de> {
@@ -53,7 +53,7 @@ impl BrainDamageType {
     //      through to something else(s) which might have some use for it.
     //
     pub fn random(bans: &TraitVec) -> Self {
-        #[derive(Debug, PartialEq, Eq, Hash)]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
         enum BDT { B1, B2, B3, B4, B5, B6 }
         impl From<i32> for BDT { fn from(value: i32) -> Self {
                 match value {
@@ -69,7 +69,7 @@ impl BrainDamageType {
             }
         }
 
-        let mut bdts = HashMap::new();
+        let mut bdts = BTreeMap::new();
         // Figure out who many BDT(s) to generate.
         let mut num = 1;
         while num > 0 {
@@ -203,16 +203,16 @@ impl SeriousWound {
     pub fn random(bans: &TraitVec) -> Self {
         match 1.d20() {
             ..=1 => Self::ImpressiveFacialScar(Stat::Dex { val: if 1.d2().is_one() {1} else {-1} }),
-            2 => Self::ImpressiveBodyScars(BodyLocation::random()),
-            3 => Self::EyePutOut(Bilateral::random_lr()),
+            2 => Self::ImpressiveBodyScars(random_body_location()),
+            3 => Self::EyePutOut(random_lr()),
             4 => Self::LoseSomeTeeth(1.d4() as u8),
-            5 => Self::EarTornOut { which: Bilateral::random_lr(), deafened_side: 1.d10() > 6 },
+            5 => Self::EarTornOut { which: random_lr(), deafened_side: 1.d10() > 6 },
             6 => Self::Disfigurement { app: Stat::App { val: -(1.d10()) }, cha: Stat::Cha { val: -(1.d10()) }},
             7 => Self::BrainDamage(BrainDamageType::random(bans)),
             8 => Self::InjuryCausesConstantPain { dex: Stat::Dex { val: -1 }, str: Stat::Str { val: -1 }, footnote: SeriousWoundFootnote::Bdtfn7 },
             9 => Self::KneeInjury { footnote1: SeriousWoundFootnote::Bdtfn7, footnote2: SeriousWoundFootnote::Bdtfn8 },
             10 => Self::BodyPartSevered({
-                let side = Bilateral::random_lr();
+                let side = random_lr();
                 match 1.d6() {
                     ..=1 => BodyLocation::Hand(side),
                     2 => BodyLocation::Arm(side),
@@ -242,4 +242,19 @@ impl SeriousWound {
             }
         }
     }
+
+    /// Lasting [physical afflictions][PhysicalAffliction] the wound leaves behind, if any.
+    ///
+    /// Note that the wound's own stat changes are not repeated in the afflictions.
+    pub fn afflictions(&self) -> Vec<PhysicalAffliction> {
+        match self {
+            Self::Combine2 { fst, snd } => [fst, snd].iter().flat_map(|w| w.afflictions()).collect(),
+            Self::Combine3 { fst, snd, trd } => [fst, snd, trd].iter().flat_map(|w| w.afflictions()).collect(),
+            Self::EyePutOut(lr) => vec![PhysicalAffliction::Blind(Some(lr.clone()))],
+            Self::EarTornOut { which, deafened_side: true } => vec![PhysicalAffliction::Deaf(Some(which.clone()))],
+            Self::BodyPartSevered(bl) => vec![PhysicalAffliction::MissingLimb(bl.clone())],
+            Self::ThroatInjury { voice_loss_percentage: 100 } => vec![PhysicalAffliction::Mute],
+            _ => vec![]
+        }
+    }
 }
\ No newline at end of file
@@ -1,14 +1,15 @@
 //! Shapes
 //! 
 //! JSON partially derived from 866.
-use std::{fmt::Display, fs};
+use std::fmt::Display;
 
-use dicebag::{DiceExt, RandomOf};
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 
 use rpgassist::{resolve::resolve_in_place::ResolveInPlace, serialize::serial_strings::deserialize_strings_to_vec};
 
+use crate::{data::{check, load_json, loaded}, dice::{DiceExt, RandomOf}, error::Result};
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Shape {
     name: String,
@@ -18,9 +19,9 @@ pub struct Shape {
     #[serde(default)] bm: bool,
 }
 
-static SHAPE_FILE: &'static str = "./data/shape.json";
+static SHAPE_FILE: &'static str = "shape.json";
 lazy_static! {
-    static ref SHAPES: Vec<Shape> = {
+    static ref SHAPES_DATA: Result<Vec<Shape>> = {
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum ShapeEntry {
@@ -34,10 +35,16 @@ lazy_static! {
             }
         }
 
-        let json = &fs::read_to_string(SHAPE_FILE).expect(format!("Error with '{SHAPE_FILE}'?!").as_str());
-        let items: Vec<ShapeEntry> = serde_jsonc::from_str(json).expect("JSON error");
-        items.into_iter().map(shaper).collect()
+        load_json::<Vec<ShapeEntry>>(SHAPE_FILE)
+            .map(|items| items.into_iter().map(shaper).collect())
     };
+    static ref SHAPES: &'static Vec<Shape> = loaded(&SHAPES_DATA);
+}
+
+/// Load [Shape] data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&SHAPES_DATA)?;
+    Ok(())
 }
 
 impl Display for Shape {
@@ -74,9 +81,8 @@ impl ResolveInPlace for Shape {
 
 #[cfg(test)]
 mod shape_tests {
-    use dicebag::RandomOf;
 
-    use crate::misc::{Shape, shape::SHAPES};
+    use crate::{dice::RandomOf, misc::{Shape, shape::SHAPES}};
 
     #[test]
     fn shape_file_data_integrity() {
@@ -3,7 +3,7 @@ use cchol_pm::{Gendered, HasName};
 use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}, serialize::serial_uf64::deserialize as uf64_deserialize};
 use serde::{Deserialize, Serialize};
 
-use crate::{StatMap, Workpad, racial::Race, social::{birth::Birth, culture::Culture, status::SocialStatus}, traits::HasCulture};
+use crate::{StatMap, Workpad, events::GiftOrLegacy, misc::Occupations, racial::Race, roll_log::RollLog, skill::Skill, social::{birth::Birth, birth_legitimacy::SiblingLegit, culture::Culture, family::FamilyStructure, status::SocialStatus}, traits::HasCulture};
 
 /// Default starting money, be it $, €, credits, gold, or something else.
 static DEFAULT_STARTING_MONEY: f64 = 1_000.0;
@@ -11,17 +11,14 @@ static DEFAULT_STARTING_MONEY: f64 = 1_000.0;
 /// (De)serializer for PC save [Race] entry.
 mod serial_pc_race {
     use rpgassist::ext::IsNamed;
-    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::{Deserialize, Deserializer, Serializer, de};
 
-    use crate::racial::{Race, race::RACES};
+    use crate::racial::Race;
 
     pub(super) fn deserialize<'de,D>(deserializer: D) -> Result<&'static Race, D::Error>
     where D: Deserializer<'de> {
         let race_name = String::deserialize(deserializer)?;
-        // fail-fast if exact name match (case ignorant) isn't found…!
-        Ok(&*RACES.iter()
-            .find(|n| n.name().to_lowercase() == race_name.to_lowercase())
-            .expect(format!("SAVE FILE: non-existent Race '{race_name}' defined!").as_str()))
+        Race::try_from_name(&race_name).map_err(de::Error::custom)
     }
 
     pub(super) fn serialize<S>(race: &&'static Race, serializer: S) -> Result<S::Ok, S::Error>
@@ -33,17 +30,14 @@ mod serial_pc_race {
 /// (De)serialized for PC save [Culture] entry.
 mod serial_pc_culture {
     use rpgassist::ext::IsNamed;
-    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::{Deserialize, Deserializer, Serializer, de};
 
-    use crate::social::culture::{CULTURES, Culture};
+    use crate::social::culture::Culture;
 
     pub(super) fn deserialize<'de,D>(deserializer: D) -> Result<&'static Culture, D::Error>
     where D: Deserializer<'de> {
         let cult_name = String::deserialize(deserializer)?;
-        // fail-fast if exact name match (case ignorant) isn't found…!
-        Ok(&*CULTURES.iter()
-            .find(|n| n.name().to_lowercase() == cult_name.to_lowercase())
-            .expect(format!("SAVE FILE: non-existent Culture '{cult_name}' defined!").as_str()))
+        Culture::try_from_name(&cult_name).map_err(de::Error::custom)
     }
 
     pub(super) fn serialize<S>(culture: &&'static Culture, serializer: S) -> Result<S::Ok, S::Error>
@@ -54,6 +48,21 @@ mod serial_pc_culture {
 
 fn get_starting_money_default() -> f64 {DEFAULT_STARTING_MONEY}
 
+/// The bits of a [PlayerCharacter] that GM-only unusual birth circumstances may have
+/// changed behind the player's back, as the player knows them.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub(crate) struct PlayerKnown {
+    pub(crate) stats: StatMap,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) skills: Vec<Skill>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) possessions: Vec<GiftOrLegacy>,
+    pub(crate) status: SocialStatus,
+    pub(crate) family: FamilyStructure,
+    #[serde(default)]
+    pub(crate) siblings: Vec<SiblingLegit>,
+}
+
 /// Player Character data lives here, obviously(?)…
 #[derive(Debug, Deserialize, Serialize, Clone, HasName, Gendered)]
 pub struct PlayerCharacter {
@@ -62,23 +71,41 @@ pub struct PlayerCharacter {
     #[serde(with = "serial_pc_race")]
     race: &'static Race,
     stats: StatMap,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skills: Vec<Skill>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    possessions: Vec<GiftOrLegacy>,
     #[serde(with = "serial_pc_culture")]
     culture: &'static Culture,
     status: SocialStatus,
     #[serde(deserialize_with = "uf64_deserialize", default = "get_starting_money_default")]
     starting_money: f64,
     birth: Birth,
+    #[serde(default, skip_serializing_if = "Occupations::is_empty")]
+    occupations: Occupations,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "RollLog::is_empty")]
+    roll_log: RollLog,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    player_known: Option<PlayerKnown>,
 } impl PlayerCharacter {
     pub fn create(workpad: &mut Workpad) -> Self {
         Self {
             name: workpad.name().into(),
             stats: workpad.get_statmap().clone(),
+            skills: workpad.get_skills().to_vec(),
+            possessions: workpad.get_possessions().to_vec(),
             status: workpad.get_social_status().clone(),
             starting_money: workpad.get_social_status().starting_money() as f64,
             birth: workpad.get_birth().clone(),
+            occupations: workpad.get_occupations().clone(),
             gender: workpad.gender(),
             race: workpad.race(),
-            culture: workpad.culture()
+            culture: workpad.culture(),
+            seed: Some(workpad.seed()),
+            roll_log: workpad.roll_log(),
+            player_known: workpad.player_known().cloned(),
         }
     }
 
@@ -115,9 +142,143 @@ pub struct PlayerCharacter {
         self
     }
 
+    /// Get [Race].
+    pub fn race(&self) -> &'static Race {
+        self.race
+    }
+
+    /// Get [Culture].
+    pub fn culture(&self) -> &'static Culture {
+        self.culture
+    }
+
+    /// Get [SocialStatus].
+    pub fn social_status(&self) -> &SocialStatus {
+        &self.status
+    }
+
+    /// Get [Birth] details.
+    pub fn birth(&self) -> &Birth {
+        &self.birth
+    }
+
+    /// Get [Occupations] — the parents' and the character's own.
+    pub fn occupations(&self) -> &Occupations {
+        &self.occupations
+    }
+
+    /// Get [StatMap].
+    pub fn stats(&self) -> &StatMap {
+        &self.stats
+    }
+
+    /// Get [skills][Skill].
+    pub fn skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    /// Get starting possessions, e.g. [gifts and legacies][GiftOrLegacy].
+    pub fn possessions(&self) -> &[GiftOrLegacy] {
+        &self.possessions
+    }
+
+    /// Get the dice seed the character was generated with — none if hand-tweaked
+    /// along the way, as then the seed alone no longer gives the same character.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Forget the dice seed.
+    pub(crate) fn forget_seed(&mut self) {
+        self.seed = None;
+    }
+
+    /// Get the [roll log][RollLog], if one was kept.
+    pub fn roll_log(&self) -> &RollLog {
+        &self.roll_log
+    }
+
+    /// Drop the [roll log][RollLog].
+    pub fn forget_roll_log(&mut self) {
+        self.roll_log = RollLog::default()
+    }
+
+    /// Get the character as the player gets to know it: without the GM-only unusual
+    /// birth circumstances nor anything they did to the character, and without the
+    /// [roll log][RollLog] and seed that would give them away.
+    pub fn player_view(&self) -> Self {
+        let mut pc = self.clone();
+        if let Some(known) = pc.player_known.take() {
+            pc.stats = known.stats;
+            pc.skills = known.skills;
+            pc.possessions = known.possessions;
+            pc.status = known.status;
+            pc.starting_money = pc.status.starting_money();
+            pc.birth.restore_family(known.family, known.siblings);
+        }
+        pc.birth.forget_gm_secrets();
+        pc.forget_roll_log();
+        pc.forget_seed();
+        pc
+    }
+
     /// See how much moneys the character has… at start.
     pub fn starting_money(&self) -> f64 {
         self.status.starting_money()
         * self.birth.starting_money_mod()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod pc_tests {
+    use serde_jsonc::to_value;
+
+    use crate::{events::{Tragedy, UnusualBirthCircumstance}, places::birthplace::PlaceOfBirth, skill::SKILLS, social::birth::Parentage};
+
+    use super::*;
+
+    #[test]
+    fn gm_secrets_leave_no_trace_in_player_view() {
+        let mut workpad = Workpad::with_seed(112);
+        workpad.set_name("Tester");
+        workpad += Race::random();
+        workpad += Gender::Female;
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        workpad += SocialStatus::random(workpad.culture());
+        let parentage = Parentage::random(&mut workpad);
+        let (family, siblings) = (to_value(parentage.family()).unwrap(), to_value(parentage.siblings()).unwrap());
+        workpad += parentage;
+        let place = PlaceOfBirth::random(&mut workpad);
+        workpad += place.clone();
+        let stats = to_value(workpad.get_statmap()).unwrap();
+        let status = to_value(workpad.get_social_status()).unwrap();
+
+        workpad.begin_gm_secrets();
+        workpad.boost_bimod(200);
+        let ubc = UnusualBirthCircumstance::random(&mut workpad);
+        assert!(matches!(ubc, UnusualBirthCircumstance::Ubc111xxx { .. }));
+        for _ in 0..5 {
+            Tragedy::random(&mut workpad);
+        }
+        workpad.end_gm_secrets();
+        // … and what comes after is for everyone to know.
+        workpad += (&SKILLS[0], 2);
+        let birth = Birth::from_parts(workpad.take_parentage(), place, vec![], vec![ubc]);
+        workpad += birth;
+        workpad += Occupations::default();
+
+        let pc = PlayerCharacter::create(&mut workpad);
+        assert_ne!(stats, to_value(pc.stats()).unwrap());
+        assert!(!pc.possessions().is_empty());
+
+        let player = pc.player_view();
+        assert_eq!(stats, to_value(player.stats()).unwrap());
+        assert!(player.possessions().is_empty());
+        assert_eq!(vec![SKILLS[0].name()], player.skills().iter().map(|s| s.name()).collect::<Vec<_>>());
+        assert_eq!(status, to_value(player.social_status()).unwrap());
+        assert_eq!(family, to_value(player.birth().family()).unwrap());
+        assert_eq!(siblings, to_value(player.birth().siblings()).unwrap());
+        assert!(player.birth().gm_unusual_birth_circumstances().is_empty());
+        assert_eq!(None, player.seed());
+        assert!(player.player_known.is_none());
+    }
+}
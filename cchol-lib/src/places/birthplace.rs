@@ -4,13 +4,21 @@
 //! # [`PlaceOfBirth`]
 //! 
 //! Encapsulates all there is to know about birthplace(s).
-use dicebag::{DiceExt, lo, HiLo};
+use std::fmt::Display;
+
+use rpgassist::ext::IsNamed;
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
 
-use crate::{Workpad, modifier::{BiMod, CuMod, LegitMod}, places::birthplace::exotic::ExoticPlaceOfBirth, social::culture::{CultureCoreType}, traits::{HasCulture, HasCultureCoreType}};
+use crate::{Workpad, dice::{DiceExt, lo}, modifier::{BiMod, CuMod, LegitMod}, places::birthplace::exotic::ExoticPlaceOfBirth, roll_log::{self, AppliedMod}, social::culture::{CultureCoreType}, traits::{HasCulture, HasCultureCoreType}};
 mod exotic;
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+/// Load and validate birthplace related data.
+pub(crate) fn try_load() -> crate::error::Result<()> {
+    exotic::try_load()
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
 pub enum PlaceOfBirth {
     FamilyHome,
     AtHealer,   AtHospital, AtHealersGuildhall, // culture specific
@@ -23,6 +31,27 @@ pub enum PlaceOfBirth {
     Exotic(ExoticPlaceOfBirth),
 }
 
+impl Display for PlaceOfBirth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FamilyHome => write!(f, "At the family home"),
+            Self::AtHealer => write!(f, "At a healer's"),
+            Self::AtHospital => write!(f, "At a hospital"),
+            Self::AtHealersGuildhall => write!(f, "At a healers' guildhall"),
+            Self::CarriageWhileTraveling => write!(f, "In a carriage while traveling"),
+            Self::Cave => write!(f, "In a cave"),
+            Self::CaveNPrim => write!(f, "In a cave, far from civilization"),
+            Self::CommonBarn => write!(f, "In a common barn"),
+            // once foreign is foreign enough.
+            Self::ForeignLand(p) if matches!(**p, Self::ForeignLand(_)) => write!(f, "{p}"),
+            Self::ForeignLand(p) => write!(f, "{p}, in a foreign land"),
+            Self::MiddleOfField => write!(f, "In the middle of a field"),
+            Self::Forest => write!(f, "In a forest"),
+            Self::Exotic(e) => write!(f, "{}", e.name()),
+        }
+    }
+}
+
 impl BiMod for PlaceOfBirth {
     fn bimod(&self) -> i32 {
         match self {
@@ -44,7 +73,9 @@ impl BiMod for PlaceOfBirth {
 
 impl PlaceOfBirth {
     pub fn random(workpad: &mut Workpad) -> Self {
-        match 1.d20() + workpad.legitmod() + workpad.cumod() {
+        let roll = 1.d20();
+        let mods = [AppliedMod::LegitMod(workpad.legitmod()), AppliedMod::CuMod(workpad.cumod())];
+        let place = match roll + workpad.legitmod() + workpad.cumod() {
             ..=6 => Self::FamilyHome,
             ..=9 => match workpad.culture().core_type() {
                 CultureCoreType::Primitive |
@@ -68,6 +99,33 @@ impl PlaceOfBirth {
             15 => Self::MiddleOfField,
             16 => Self::Forest,
             _  => Self::Exotic(ExoticPlaceOfBirth::random(workpad))
+        };
+        roll_log::record("110", roll, &mods, place.as_ref());
+        place
+    }
+
+    /// All the places of birth to hand-pick from, mundane ones first.
+    pub fn choices() -> Vec<String> {
+        Self::MUNDANE.iter()
+            .map(|p| p.to_string())
+            .chain(exotic::names().into_iter().map(String::from))
+            .collect()
+    }
+
+    /// Hand-pick place of birth by `index` into [choices][PlaceOfBirth::choices].
+    pub fn pick(index: usize, workpad: &mut Workpad) -> Option<Self> {
+        if let Some(place) = Self::MUNDANE.get(index) {
+            return Some(place.clone());
         }
+        ExoticPlaceOfBirth::pick(index - Self::MUNDANE.len(), workpad).map(Self::Exotic)
     }
-}
\ No newline at end of file
+
+    const MUNDANE: [Self; 10] = [
+        Self::FamilyHome,
+        Self::AtHealer, Self::AtHospital, Self::AtHealersGuildhall,
+        Self::CarriageWhileTraveling,
+        Self::Cave, Self::CommonBarn, Self::CaveNPrim,
+        Self::MiddleOfField,
+        Self::Forest,
+    ];
+}
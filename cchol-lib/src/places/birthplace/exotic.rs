@@ -1,48 +1,62 @@
 //! 111: Exotic Birth Location
 
-use std::{collections::HashMap, fs};
+use std::collections::BTreeMap;
 
 use cchol_pm::{HasBiMod, HasName, HasRollRange};
-use dicebag::{DiceExt, InclusiveRandomRange, IsOne};
+use dicebag::IsOne;
 use lazy_static::lazy_static;
 use rpgassist::ext::IsNamed;
 use serde::{Deserialize, Deserializer, Serialize, de::{self, Visitor}};
 
-use crate::{Workpad, modifier::{BiMod, CuMod, LegitMod}, roll_range::{RollRange, UseRollRange}, serialize::{deserialize_fixed_cr_range, validate_cr_ranges}, skill::native_env::NativeOf, string_manip::resolve_name_hooks, traits::HasCulture};
+use crate::{Workpad, data::{blame_packs, check, load_json, loaded}, error::{CcholError, Result}, dice::{DiceExt, InclusiveRandomRange}, modifier::{BiMod, CuMod, LegitMod}, roll_log::{self, AppliedMod}, roll_range::{RollRange, UseRollRange}, serialize::{default_pc_save_cr_range, deserialize_fixed_cr_range, try_validate_cr_ranges, validate_cr_ranges}, skill::native_env::NativeOf, string_manip::{resolve_name_hooks, try_check_name_hooks}, traits::HasCulture};
 
-static EXOTIC_LOCATIONS_FILE: &'static str = "./data/ebloc.json";
+static EXOTIC_LOCATIONS_FILE: &'static str = "ebloc.json";
 lazy_static! {
-    static ref EXOTIC_LOCATIONS: Vec<ExoticPlaceOfBirth> = {
-        let locs: Vec<ExoticPlaceOfBirth> = serde_jsonc::from_str(
-                &fs::read_to_string(EXOTIC_LOCATIONS_FILE).expect(format!("Error with '{EXOTIC_LOCATIONS_FILE}'?!").as_str())
-            ).expect("JSON error");
-        
-        // ensure that all the wee EPOBAltChoice entries have a proper name in case EPOBAlt.extends_base is `false`.
-        // TODO: as a local function here now, but might have use elsewhere later as a generic… shall see.
-        fn validate_full_name_presence(locations: &Vec<ExoticPlaceOfBirth>) {
-            for location in locations {
-                if let Some(alt_data) = &location.alt {
-                    // RULE: if extends_base is `false`, all choices *must* have a proper non-empty name.
-                    if !alt_data.extends_base {
-                        for (roll, choice) in &alt_data.choices {
-                            // Check if the name is `None` or if the name is an empty string…
-                            if choice.name.is_none() || choice.name.as_ref().is_some_and(|s| s.is_empty()) {
-                                panic!(
-                                    "DATA ERROR: Choice '{roll}' for '{}' MUST have a name when 'extends_base' is `false` or omitted; otherwise the name becomes blank.",
-                                    location.name
-                                );
-                            }
-                        }
+    static ref EXOTIC_LOCATIONS_DATA: Result<Vec<ExoticPlaceOfBirth>> = load_json(EXOTIC_LOCATIONS_FILE)
+        .and_then(|locs| validate_full_name_presence(locs).map_err(blame_packs(EXOTIC_LOCATIONS_FILE)));
+    static ref EXOTIC_LOCATIONS: &'static Vec<ExoticPlaceOfBirth> = loaded(&EXOTIC_LOCATIONS_DATA);
+
+    static ref EXOTIC_RANGE: RollRange = validate_cr_ranges("EXOTIC_LOCATIONS", &EXOTIC_LOCATIONS, None);
+}
+
+/// Ensure that all the wee [EPOBAltChoice] entries have a proper name in case
+/// [EPOBAlt] `extends_base` is `false`.
+fn validate_full_name_presence(locations: Vec<ExoticPlaceOfBirth>) -> Result<Vec<ExoticPlaceOfBirth>> {
+    for location in &locations {
+        if let Some(alt_data) = &location.alt {
+            // RULE: if extends_base is `false`, all choices *must* have a proper non-empty name.
+            if !alt_data.extends_base {
+                for (roll, choice) in &alt_data.choices {
+                    // Check if the name is `None` or if the name is an empty string…
+                    if choice.name.is_none() || choice.name.as_ref().is_some_and(|s| s.is_empty()) {
+                        return Err(CcholError::Validation {
+                            table: "EXOTIC_LOCATIONS".into(),
+                            reason: format!("Choice '{roll}' for '{}' MUST have a name when 'extends_base' is `false` or omitted; otherwise the name becomes blank.", location.name)
+                        });
                     }
                 }
             }
         }
-        validate_full_name_presence(&locs);
+    }
+    Ok(locations)
+}
 
-        locs
-    };
+/// Load and validate [ExoticPlaceOfBirth] data.
+pub(crate) fn try_load() -> Result<()> {
+    let locations = check(&EXOTIC_LOCATIONS_DATA)?;
+    try_validate_cr_ranges("EXOTIC_LOCATIONS", locations, None).map_err(blame_packs(EXOTIC_LOCATIONS_FILE))?;
+    for location in locations {
+        let alt_names = location.alt.iter().flat_map(|alt| alt.choices.values()).map(|choice| choice.name());
+        for name in std::iter::once(location.name()).chain(alt_names) {
+            try_check_name_hooks("EXOTIC_LOCATIONS", name).map_err(blame_packs(EXOTIC_LOCATIONS_FILE))?;
+        }
+    }
+    Ok(())
+}
 
-    static ref EXOTIC_RANGE: RollRange = validate_cr_ranges("EXOTIC_LOCATIONS", &EXOTIC_LOCATIONS, None);
+/// Names of all the exotic places of birth.
+pub(crate) fn names() -> Vec<&'static str> {
+    EXOTIC_LOCATIONS.iter().map(|place| place.name()).collect()
 }
 
 static MAX_EPOBALT_CHOICES: usize = 20;
@@ -68,7 +82,7 @@ where D: Deserializer<'de>
 ///   "#": "Barbaz"
 /// }
 /// ```
-fn deserialize_epobalt_choicemap<'de,D>(deserializer:D) -> Result<HashMap<usize, EPOBAltChoice>, D::Error>
+fn deserialize_epobalt_choicemap<'de,D>(deserializer:D) -> Result<BTreeMap<usize, EPOBAltChoice>, D::Error>
 where D: Deserializer<'de>
 {
     struct NumKeyVisitor;
@@ -81,7 +95,7 @@ where D: Deserializer<'de>
     }
 
     impl <'de> Visitor<'de> for NumKeyVisitor {
-        type Value = HashMap<usize, EPOBAltChoice>;
+        type Value = BTreeMap<usize, EPOBAltChoice>;
         fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
             formatter.write_str("a map with numeric string keys")
         }
@@ -89,7 +103,7 @@ where D: Deserializer<'de>
         fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
         where A: de::MapAccess<'de>,
         {
-            let mut hash_map = HashMap::new();
+            let mut hash_map = BTreeMap::new();
             while let Some(key) = map.next_key::<String>()? {
                 hash_map.insert(
                     // usize numeric key for speedy dice roll based map checking...
@@ -130,7 +144,7 @@ impl IsNamed for EPOBAltChoice {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EPOBAlt {
     #[serde(deserialize_with = "dice_size_clamp")] dice_size: usize,
-    #[serde(default, deserialize_with = "deserialize_epobalt_choicemap")] choices: HashMap<usize, EPOBAltChoice>,
+    #[serde(default, deserialize_with = "deserialize_epobalt_choicemap")] choices: BTreeMap<usize, EPOBAltChoice>,
     #[serde(default)] extends_base: bool,
 } impl EPOBAlt {
     pub fn random(&self) -> Option<&EPOBAltChoice> {
@@ -144,23 +158,36 @@ pub struct ExoticPlaceOfBirth {
     name: String,
     alt: Option<EPOBAlt>,
     base_environment: NativeOf,
-    #[serde(deserialize_with = "deserialize_fixed_cr_range")] _cr_range: RollRange,
+    #[serde(deserialize_with = "deserialize_fixed_cr_range", default = "default_pc_save_cr_range", skip_serializing)] _cr_range: RollRange,
     #[serde(default)] origin_hook: Option<String>,
     #[serde(default)] combined_with: Option<Box<ExoticPlaceOfBirth>>,
 } impl ExoticPlaceOfBirth {
     pub fn random(workpad: &mut Workpad) -> Self {
-        let roll = (EXOTIC_RANGE.random_of() + workpad.cumod() - workpad.legitmod())
+        let raw = EXOTIC_RANGE.random_of();
+        let roll = (raw + workpad.cumod() - workpad.legitmod())
             .max(*EXOTIC_RANGE.start())
             .min(*EXOTIC_RANGE.end());
-        EXOTIC_LOCATIONS.iter()
+        let suitable: Vec<&ExoticPlaceOfBirth> = EXOTIC_LOCATIONS.iter()
             .filter(|place| {
                 !workpad.race().incompatible_with_env(&place.base_environment) &&
                 !workpad.culture().incompatible_with_env(&place.base_environment)
             })
+            .collect();
+        if suitable.is_empty() {
+            panic!("Err, no suitable exotic location found for '{}' at all?!", workpad.race().name())
+        }
+        // rolled location might be unsuitable for race/culture, in which case we pick one of the suitable ones.
+        let place = (*suitable.iter()
             .find(|place| place.roll_range().contains(&roll))
-            .expect(format!("Err, no suitable exotic location found for '{}' with roll of '{roll}'", workpad.race().name()).as_str())
-            .clone()
-            .resolve(workpad)
+            .unwrap_or_else(|| &suitable[1.d(suitable.len()) - 1]))
+            .clone();
+        roll_log::record("111", raw, &[AppliedMod::CuMod(workpad.cumod()), AppliedMod::LegitMod(-workpad.legitmod())], place.name());
+        place.resolve(workpad)
+    }
+
+    /// Hand-pick exotic place of birth by `index` into [names].
+    pub(crate) fn pick(index: usize, workpad: &mut Workpad) -> Option<Self> {
+        Some(EXOTIC_LOCATIONS.get(index)?.clone().resolve(workpad))
     }
 
     /// Resolve various things in place…
@@ -168,11 +195,13 @@ pub struct ExoticPlaceOfBirth {
         // check for alt-variations
         if let Some(alt_data) = &self.alt {
             if let Some(alt) = alt_data.random() {
-                // no point in appending nonexisting or empty string…
-                if (alt.name.is_some() && !alt.name().is_empty()) && alt_data.extends_base {
-                    self.name = format!("{} {}", self.name, alt.name());
-                } else {
-                    self.name = alt.name().into();
+                // no point in appending (or switching to) nonexisting or empty string…
+                if alt.name.is_some() && !alt.name().is_empty() {
+                    if alt_data.extends_base {
+                        self.name = format!("{} {}", self.name, alt.name());
+                    } else {
+                        self.name = alt.name().into();
+                    }
                 }
 
                 if let Some(env) = &alt.base_environment {
@@ -228,6 +257,8 @@ mod exotic_birthplace_tests {
         let mut workpad = Workpad::new();
         workpad += Race::random();
         workpad += Culture::random_max_bound(workpad.race().max_culture());
+        // combined places roll another exotic place, which needs LegitMod.
+        workpad.set_illegitimacy_info(None);
         struct Foobar;
         let _ = env_logger::try_init();
         let mut i = 0;
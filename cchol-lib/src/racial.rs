@@ -2,4 +2,12 @@ pub mod monster;
 pub use monster::Monster;
 
 pub mod race;
-pub use race::Race;
\ No newline at end of file
+pub use race::Race;
+
+use crate::error::Result;
+
+/// Load and validate all [Race] and [Monster] data.
+pub(crate) fn try_load() -> Result<()> {
+    race::try_load()?;
+    monster::try_load()
+}
@@ -1,31 +1,32 @@
 //! 756: Monsters
-use std::fs;
-
 use cchol_pm::{Gendered, HasName, HasRollRange};
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use rpgassist::{gender::{Gender, HasGender}, ext::IsNamed};
 use serde::{Deserialize, Serialize};
 
-use crate::{racial::{Race, race::RACES}, roll_range::*, serialize::{default_pc_save_cr_range, deserialize_cr_range, validate_cr_ranges}};
+use crate::{data::{blame_packs, check, load_json, loaded}, dice::{DiceExt, random_gender}, error::Result, racial::{Race, race::RACES}, roll_range::*, serialize::{default_pc_save_cr_range, deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}};
 
-static MONSTER_FILE: &'static str = "./data/monsters.json";
+static MONSTER_FILE: &'static str = "monsters.json";
 lazy_static! {
-    static ref MONSTERS: Vec<Monster>
-        = serde_jsonc::from_str(
-            &fs::read_to_string(MONSTER_FILE)
-                .expect(format!("Error with '{MONSTER_FILE}'?!").as_str())
-        ).expect("JSON error");
+    static ref MONSTERS_DATA: Result<Vec<Monster>> = load_json(MONSTER_FILE);
+    static ref MONSTERS: &'static Vec<Monster> = loaded(&MONSTERS_DATA);
+
+    static ref MONSTER_RANGE: RollRange = validate_cr_ranges("MONSTERS", &MONSTERS, None);
+}
 
-    static ref MONSTER_RANGE: RollRange = validate_cr_ranges("MONSTERS", &*MONSTERS, None);
+/// Load and validate [Monster] data.
+pub(crate) fn try_load() -> Result<()> {
+    try_validate_cr_ranges("MONSTERS", check(&MONSTERS_DATA)?, None).map_err(blame_packs(MONSTER_FILE))?;
+    Ok(())
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, HasRollRange, Gendered, HasName)]
 pub struct Monster {
+    #[serde(default)]
     name: String,
     #[serde(default, skip_serializing)]
     variants: Vec<String>,
-    #[serde(deserialize_with = "deserialize_cr_range")]
+    #[serde(deserialize_with = "deserialize_cr_range", default = "default_pc_save_cr_range", skip_serializing)]
     _cr_range: RollRange,
     #[serde(default)]
     gender: Gender,
@@ -37,7 +38,7 @@ impl Monster {
         // +4 → accommodate for a) Race (some beastman), b) Race (reptilians), c/d) GM specials 756A & 756B.
         let roll = 1.d((*(*MONSTER_RANGE).end() + 4) as usize);
         if roll > *MONSTER_RANGE.end() {
-            let gender = Gender::random();
+            let gender = random_gender();
             match roll - *MONSTER_RANGE.end() - 1 {
                 ..=0 => return Self { name: {
                     let bs = RACES.iter().filter(|r| r.is_beastman()).collect::<Vec<&'static Race>>();
@@ -1,45 +1,22 @@
 //! 101: Race
-use std::fs;
-
 use cchol_pm::HasRollRange;
 use lazy_static::lazy_static;
 use rpgassist::{gender::{Gender, GenderBias, HasGenderBias}, ext::IsNamed};
 use serde::{Deserialize, Deserializer, Serialize, de};
 
-use crate::{events::RacialEvent, roll_range::*, modifier::CuMod, serialize::{default_pc_save_cr_range, deserialize_fixed_cr_range, validate_cr_ranges, deserialize_nativeofs_to_vec}, skill::native_env::NativeOf, social::{LineageStrictness, culture::{CULTURE_DEFAULT_MAX, CULTURES, Culture}, nobility::Noble, status::SocialStatus}};
+use crate::{data::{blame_packs, check, load_json, loaded}, dice::random_gender_biased, error::{CcholError, Result}, events::RacialEvent, roll_log, roll_range::*, modifier::CuMod, serialize::{default_pc_save_cr_range, deserialize_fixed_cr_range, try_validate_cr_ranges, validate_cr_ranges, deserialize_nativeofs_to_vec}, skill::native_env::NativeOf, social::{LineageStrictness, culture::{CULTURE_DEFAULT_MAX, CULTURES, Culture}, nobility::Noble, status::SocialStatus}};
 
-static RACE_FILE: &'static str = "./data/race.json";
+static RACE_FILE: &'static str = "race.json";
 lazy_static! {
-    pub(crate) static ref RACES: Vec<Race> = {
-        let races: Vec<Race> = serde_jsonc::from_str::<Vec<Race>>(
-            &fs::read_to_string(RACE_FILE)
-                .expect(format!("No '{}' found?!", RACE_FILE).as_str())
-        ).expect("JSON fail!");
-
-        let mut something_failed = false;
-        for r in &races {
-            if r.roll_range() == &(0..=0) {
-                something_failed = true;
-                log::error!("DATA VALIDATION: Race '{}' is MISSING its '_cr_range' field in '{RACE_FILE}'!", r.name());
-            }
-        }
-        if something_failed {panic!("Cannot continue before someone fixes JSON in '{RACE_FILE}'…")}
-
-        races
-    };
+    static ref RACES_DATA: Result<Vec<Race>> = load_json(RACE_FILE)
+        .and_then(|races| validate_races(races).map_err(blame_packs(RACE_FILE)));
+    pub(crate) static ref RACES: &'static Vec<Race> = loaded(&RACES_DATA);
 
     /// The 'default' race to use when non-random race is required, which
     /// usually is "human" but can be defined to be whatever else is present.
-    static ref RACE_DEFAULT: &'static Race = {
-        let defaults: Vec<&'static Race> = RACES.iter()
-            .filter(|r| r.is_default())
-            .collect();
-        match defaults.len() {
-            0 => panic!("DATA VALIDATION: no default Race specified!"),
-            1 => defaults[0],
-            _ => panic!("DATA VALIDATION: \"There can be only one!\" - which isn't true in this case. Too many defaults ({}) defined!", defaults.len())
-        }
-    };
+    static ref RACE_DEFAULT: &'static Race = RACES.iter()
+        .find(|r| r.is_default())
+        .expect("DATA VALIDATION: no default Race specified!");
 
     /// Dice type to use for [Race] [random][Race::random]'izing.
     static ref RACE_RANGE: RollRange = validate_cr_ranges("RACES", &RACES, None);
@@ -56,6 +33,40 @@ lazy_static! {
     };
 }
 
+/// Validate freshly loaded [Race] data.
+fn validate_races(races: Vec<Race>) -> Result<Vec<Race>> {
+    let invalid = |reason: String| CcholError::Validation { table: "RACES".into(), reason };
+
+    let mut something_failed = false;
+    for r in &races {
+        if r.roll_range() == &(0..=0) {
+            something_failed = true;
+            log::error!("DATA VALIDATION: Race '{}' is MISSING its '_cr_range' field in '{RACE_FILE}'!", r.name());
+        }
+    }
+    if something_failed {
+        return Err(invalid(format!("Cannot continue before someone fixes JSON in '{RACE_FILE}'…")))
+    }
+
+    match races.iter().filter(|r| r.is_default()).count() {
+        0 => return Err(invalid("no default Race specified!".into())),
+        1 => (),
+        n => return Err(invalid(format!("\"There can be only one!\" - which isn't true in this case. Too many defaults ({n}) defined!")))
+    }
+
+    if !races.iter().any(|r| r.name().to_lowercase() == "human") {
+        return Err(invalid("Where'd the hummies go?!".into()))
+    }
+
+    Ok(races)
+}
+
+/// Load and validate [Race] data.
+pub(crate) fn try_load() -> Result<()> {
+    try_validate_cr_ranges("RACES", check(&RACES_DATA)?, None).map_err(blame_packs(RACE_FILE))?;
+    Ok(())
+}
+
 /// Deserialize a [Race]'s max culture, if any.
 fn deserialize_race_max_culture<'de,D>(deserializer: D) -> Result<Option<&'static Culture>, D::Error>
 where D: Deserializer<'de> {
@@ -143,12 +154,16 @@ impl Race {
 
     /// Get a random [Race].
     pub fn random() -> &'static Race {
-        RACES.get_random_in_range(&*RACE_RANGE)
+        let (roll, race) = RACES.roll_in_range(&*RACE_RANGE);
+        roll_log::record("101", roll, &[], race.name());
+        race
     }
 
     /// Get a random non-human [Race].
     pub fn random_nonhuman() -> &'static Race {
-        RACES.get_random_in_range(&*RACE_RANGE_NONHUMAN)
+        let (roll, race) = RACES.roll_in_range(&*RACE_RANGE_NONHUMAN);
+        roll_log::record("101", roll, &[], race.name());
+        race
     }
 
     /// Shift culture one way or the other if the given `culture` doesn't
@@ -192,10 +207,15 @@ impl Race {
         if name.is_none() {
             return Self::random();
         }
-        let name = name.unwrap();
+        Self::try_from_name(name.unwrap())
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Get a race by `name`, if such exists.
+    pub fn try_from_name(name: &str) -> Result<&'static Self> {
         RACES.iter()
             .find(|r| r.name().to_lowercase() == name.to_lowercase())
-            .expect(format!("No race called '{name}' found!").as_str())
+            .ok_or_else(|| CcholError::UnknownName { kind: "race", name: name.into() })
     }
 
     /// Get a race by (optional) `name`.
@@ -249,7 +269,7 @@ impl Race {
         if let Some(fg) = &self.forced_gender {
             return *fg;
         }
-        Gender::random_biased(self.gender_bias)
+        random_gender_biased(self.gender_bias)
     }
 
     /// Adjust `gender` to conform with the [Race] specs, if needed.
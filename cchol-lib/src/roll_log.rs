@@ -0,0 +1,183 @@
+//! Roll audit log.
+//!
+//! Keeps tabs on every (major) table lookup made during character generation:
+//! which table, what the die said, which modifiers got applied, and what
+//! entry got picked in the end. Handy for answering the eternal
+//! "*why is my character a street kid?*"
+//!
+//! Like the [dice][crate::dice] themselves, the log is per-thread as plenty of
+//! the tables get rolled without a [Workpad][crate::Workpad] at hand.
+
+use std::{cell::RefCell, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+thread_local! {
+    static LOG: RefCell<RollLog> = RefCell::new(RollLog::default());
+}
+
+/// A modifier applied on top of a raw die roll.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum AppliedMod {
+    BiMod(i32),
+    /// One-shot **BiMod** boost, see [Workpad::boost_bimod][crate::Workpad::boost_bimod].
+    BiModBoost(i32),
+    CuMod(i32),
+    LegitMod(i32),
+    SolMod(i32),
+    TiMod(i32),
+} impl AppliedMod {
+    /// Get the modifier's value.
+    pub fn value(&self) -> i32 {
+        match self {
+            Self::BiMod(v)      |
+            Self::BiModBoost(v) |
+            Self::CuMod(v)      |
+            Self::LegitMod(v)   |
+            Self::SolMod(v)     |
+            Self::TiMod(v) => *v
+        }
+    }
+}
+
+impl Display for AppliedMod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BiMod(v)      => write!(f, "BiMod {v:+}"),
+            Self::BiModBoost(v) => write!(f, "BiMod boost {v:+}"),
+            Self::CuMod(v)      => write!(f, "CuMod {v:+}"),
+            Self::LegitMod(v)   => write!(f, "LegitMod {v:+}"),
+            Self::SolMod(v)     => write!(f, "SolMod {v:+}"),
+            Self::TiMod(v)      => write!(f, "TiMod {v:+}"),
+        }
+    }
+}
+
+/// A single table lookup.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TableRoll {
+    table: String,
+    roll: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    modifiers: Vec<AppliedMod>,
+    result: String,
+} impl TableRoll {
+    /// Table "number", e.g. `101`, `758`, etc.
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    /// Raw die result, sans modifiers.
+    pub fn roll(&self) -> i32 {
+        self.roll
+    }
+
+    /// Modifiers applied, if any.
+    pub fn modifiers(&self) -> &[AppliedMod] {
+        &self.modifiers
+    }
+
+    /// Roll total, modifiers included.
+    pub fn total(&self) -> i32 {
+        self.roll + self.modifiers.iter().map(AppliedMod::value).sum::<i32>()
+    }
+
+    /// The entry the roll landed on.
+    pub fn result(&self) -> &str {
+        &self.result
+    }
+}
+
+impl Display for TableRoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.table, self.roll)?;
+        for m in &self.modifiers {
+            write!(f, ", {m}")?;
+        }
+        if !self.modifiers.is_empty() {
+            write!(f, " = {}", self.total())?;
+        }
+        write!(f, " → {}", self.result)
+    }
+}
+
+/// The whole chain of [table rolls][TableRoll], in order of appearance.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(transparent)]
+pub struct RollLog(Vec<TableRoll>);
+
+impl RollLog {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, TableRoll> {
+        self.0.iter()
+    }
+}
+
+impl Display for RollLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in &self.0 {
+            writeln!(f, "{r}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Record a table lookup.
+pub(crate) fn record(table: &str, roll: i32, modifiers: &[AppliedMod], result: impl Display) {
+    let entry = TableRoll {
+        table: table.into(),
+        roll,
+        modifiers: modifiers.to_vec(),
+        result: result.to_string(),
+    };
+    log::debug!("Roll {entry}");
+    LOG.with_borrow_mut(|log| log.0.push(entry));
+}
+
+/// Start over with an empty log.
+pub(crate) fn clear() {
+    LOG.with_borrow_mut(|log| log.0.clear());
+}
+
+/// Get a "bookmark" of the log as it is now.
+pub(crate) fn mark() -> usize {
+    LOG.with_borrow(|log| log.0.len())
+}
+
+/// Get the entries recorded since `mark`.
+pub(crate) fn since(mark: usize) -> Vec<TableRoll> {
+    LOG.with_borrow(|log| log.0.iter().skip(mark).cloned().collect())
+}
+
+/// Forget everything recorded since `mark`.
+pub(crate) fn rewind(mark: usize) {
+    LOG.with_borrow_mut(|log| log.0.truncate(mark));
+}
+
+/// Get a copy of the log so far.
+pub(crate) fn snapshot() -> RollLog {
+    LOG.with_borrow(|log| log.clone())
+}
+
+#[cfg(test)]
+mod roll_log_tests {
+    use super::*;
+
+    #[test]
+    fn record_and_total() {
+        clear();
+        record("106", 12, &[AppliedMod::CuMod(3)], "Orphanage");
+        let log = snapshot();
+        assert_eq!(1, log.len());
+        let r = log.iter().next().unwrap();
+        assert_eq!(15, r.total());
+        assert_eq!("106: 12, CuMod +3 = 15 → Orphanage", r.to_string());
+    }
+}
@@ -1,6 +1,7 @@
-use dicebag::{InclusiveRandomRange, RandomOf};
 use rpgassist::ext::GetTypeName;
 
+use crate::dice::{InclusiveRandomRange, RandomOf};
+
 pub type RollRange = std::ops::RangeInclusive<i32>;
 
 pub trait UseRollRange {
@@ -8,14 +9,19 @@ pub trait UseRollRange {
 }
 
 pub(crate) trait RollInRollRange<T> {
-    fn get_random_in_range(&self, range: &RollRange) -> &T;
+    fn get_random_in_range(&self, range: &RollRange) -> &T {
+        self.roll_in_range(range).1
+    }
+
+    /// Same as `get_random_in_range()`, but hand out the raw roll too.
+    fn roll_in_range(&self, range: &RollRange) -> (i32, &T);
 }
 
 impl <T> RollInRollRange<T> for Vec<T>
 where
     T: UseRollRange,
 {
-    fn get_random_in_range(&self, range: &RollRange) -> &T {
+    fn roll_in_range(&self, range: &RollRange) -> (i32, &T) {
         let roll = range.random_of();
         let found = self.iter()
             .filter(|r| r.roll_range().contains(&roll))
@@ -23,6 +29,6 @@ where
         if found.is_empty() {
             panic!("{} - roll '{roll}' out of range or some other logic failure…", self.type_name())
         }
-        found.random_of()
+        (roll, found.random_of())
     }
 }
\ No newline at end of file
@@ -0,0 +1,249 @@
+//! # Save files
+//!
+//! [PlayerCharacter] saves come wrapped in a versioned envelope:
+//!
+//! ```jsonc
+//! { "version": 2, "character": { "name": "Bob", … } }
+//! ```
+//!
+//! Saves from older versions get migrated, one version at a time, up to
+//! [SAVE_VERSION] when [load]ed. Bare characters (plain [PlayerCharacter]
+//! JSON from before the envelope) count as version `0`.
+//!
+//! A save referring to data (race, culture, …) that no longer exists gives
+//! a [CcholError] instead of a panic.
+
+use std::path::Path;
+
+use serde::Serialize;
+use serde_jsonc::{Value, json};
+
+use crate::{data, error::{CcholError, Result}, pc::PlayerCharacter, racial::Race, social::culture::Culture};
+
+/// Current save format version.
+pub const SAVE_VERSION: u32 = 2;
+
+/// Migration steps: `MIGRATIONS[n]` takes a version `n` save to version `n + 1`.
+static MIGRATIONS: [fn(Value) -> Result<Value>; SAVE_VERSION as usize] = [
+    v0_to_v1,
+    v1_to_v2,
+];
+
+#[derive(Serialize)]
+struct SaveFile<'a> {
+    version: u32,
+    character: &'a PlayerCharacter,
+}
+
+fn invalid(reason: impl ToString) -> CcholError {
+    CcholError::SaveFile { reason: reason.to_string() }
+}
+
+/// Save `pc` as (pretty) JSON.
+pub fn save(pc: &PlayerCharacter) -> Result<String> {
+    serde_jsonc::to_string_pretty(&SaveFile { version: SAVE_VERSION, character: pc })
+        .map_err(invalid)
+}
+
+/// Save `pc` into `file`.
+pub fn save_to(file: impl AsRef<Path>, pc: &PlayerCharacter) -> Result<()> {
+    std::fs::write(file, save(pc)? + "\n").map_err(invalid)
+}
+
+/// Load a [PlayerCharacter] from `save`, migrating it to [SAVE_VERSION] if needed.
+pub fn load(save: &str) -> Result<PlayerCharacter> {
+    data::load_data()?;
+    let mut save: Value = serde_jsonc::from_str(save).map_err(invalid)?;
+    let version = version_of(&save)?;
+    if version > SAVE_VERSION {
+        return Err(invalid(format!("version {version} is newer than what we know of ({SAVE_VERSION})")));
+    }
+    for migrate in &MIGRATIONS[version as usize..] {
+        save = migrate(save)?;
+    }
+
+    let character = save.get_mut("character")
+        .map(Value::take)
+        .ok_or_else(|| invalid("no character in it"))?;
+    // check the name references first to get a proper error out of them.
+    if let Some(race) = character.get("race").and_then(Value::as_str) {
+        Race::try_from_name(race)?;
+    }
+    if let Some(culture) = character.get("culture").and_then(Value::as_str) {
+        Culture::try_from_name(culture)?;
+    }
+    serde_jsonc::from_value(character).map_err(invalid)
+}
+
+/// Load a [PlayerCharacter] from `file`.
+pub fn load_from(file: impl AsRef<Path>) -> Result<PlayerCharacter> {
+    load(&std::fs::read_to_string(file).map_err(invalid)?)
+}
+
+/// Figure out which version `save` is.
+fn version_of(save: &Value) -> Result<u32> {
+    match save.get("version") {
+        Some(v) => v.as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| invalid(format!("bogus version {v}"))),
+        // a bare character?
+        None if save.get("name").is_some() => Ok(0),
+        None => Err(invalid("not a save file"))
+    }
+}
+
+/// Wrap a bare character into the envelope.
+/// 
+/// Bare characters also came with a few things that didn't deserialize back:
+/// `{"start", "end"}` style `_cr_range` in wealth and place of birth, and
+/// `{"WaterStructure": …}`/`{"Choice": …}` style native environments.
+fn v0_to_v1(mut save: Value) -> Result<Value> {
+    fn fix_place(place: &mut Value) {
+        match place {
+            Value::Object(map) => {
+                map.remove("_cr_range");
+                for (key, value) in map.iter_mut() {
+                    match key.as_str() {
+                        "base_environment" => fix_native_of(value),
+                        _ => fix_place(value)
+                    }
+                }
+            },
+            Value::Array(list) => list.iter_mut().for_each(fix_place),
+            _ => ()
+        }
+    }
+
+    fn fix_native_of(native_of: &mut Value) {
+        if let Some(specific) = native_of.pointer("/WaterStructure/specific").cloned() {
+            *native_of = specific;
+        } else if let Some(choice) = native_of.get_mut("Choice").map(Value::take) {
+            *native_of = choice;
+        }
+        if let Value::Object(map) = native_of {
+            map.values_mut().for_each(fix_native_of);
+        }
+    }
+
+    if let Some(wealth) = save.pointer_mut("/status/wealth").and_then(Value::as_object_mut) {
+        wealth.remove("_cr_range");
+    }
+    if let Some(place) = save.pointer_mut("/birth/place_of_birth") {
+        fix_place(place);
+    }
+    Ok(json!({ "version": 1, "character": save }))
+}
+
+/// Set the GM-only unusual birth circumstances apart from the player-known ones.
+///
+/// Version 1 had them all in one list, GM-only ones last. How many of them were
+/// for the GM only can be told by the roll log (table 112) — without one, all
+/// of them stay known to the player.
+fn v1_to_v2(mut save: Value) -> Result<Value> {
+    let gm_count = save.pointer("/character/roll_log")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|roll| roll.get("table").and_then(Value::as_str) == Some("112"))
+        .filter_map(|roll| roll.get("result")?.as_str()?
+            .strip_suffix(" for GM only")?
+            .rsplit(", ").next()?
+            .parse::<usize>().ok())
+        .next()
+        .unwrap_or(0);
+    if let Some(birth) = save.pointer_mut("/character/birth").and_then(Value::as_object_mut)
+        && gm_count > 0
+        && !birth.contains_key("gm_unusual_birth_circumstances")
+        && let Some(Value::Array(ubcs)) = birth.get_mut("unusual_birth_circumstances")
+    {
+        let gm_ubcs = ubcs.split_off(ubcs.len().saturating_sub(gm_count));
+        birth.insert("gm_unusual_birth_circumstances".into(), Value::Array(gm_ubcs));
+    }
+    save["version"] = 2.into();
+    Ok(save)
+}
+
+#[cfg(test)]
+mod save_tests {
+    use crate::{GenerationOptions, generate_player_background};
+
+    use super::*;
+
+    fn tester() -> PlayerCharacter {
+        let mut options = GenerationOptions::new("Tester");
+        options.with_seed(42);
+        generate_player_background(&options)
+    }
+
+    #[test]
+    fn round_trip() {
+        let pc = tester();
+        let saved = save(&pc).unwrap();
+        assert!(saved.contains(&format!("\"version\": {SAVE_VERSION}")));
+        assert_eq!(saved, save(&load(&saved).unwrap()).unwrap());
+        // deities, monsters and such come with table bits that don't belong in a save.
+        for seed in 0..300 {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed);
+            let saved = save(&generate_player_background(&options)).unwrap();
+            assert_eq!(saved, save(&load(&saved).unwrap()).unwrap(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn bare_character_gets_migrated() {
+        let pc = tester();
+        let bare = serde_jsonc::to_string(&pc).unwrap();
+        assert_eq!(save(&pc).unwrap(), save(&load(&bare).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn v0_oddities_get_fixed() {
+        let mut bare: Value = serde_jsonc::from_str(&serde_jsonc::to_string(&tester()).unwrap()).unwrap();
+        bare["status"]["wealth"]["_cr_range"] = json!({ "start": 1, "end": 20 });
+        bare["birth"]["place_of_birth"] = json!({ "Exotic": {
+            "bimod": 5, "name": "On a ship", "alt": null, "_cr_range": { "start": 1, "end": 1 },
+            "base_environment": { "Choice": {
+                "primary": { "WaterStructure": { "specific": "ship" } },
+                "secondary": "Urban"
+            }},
+        }});
+        let pc = load(&bare.to_string()).unwrap();
+        assert_eq!("On a ship", pc.birth().place_of_birth().to_string());
+    }
+
+    #[test]
+    fn v1_gm_secrets_get_set_apart() {
+        let pc = (0..100).map(|seed| {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed).with_roll_log();
+            generate_player_background(&options)
+        }).find(|pc| !pc.birth().gm_unusual_birth_circumstances().is_empty()).unwrap();
+        let saved = save(&pc).unwrap();
+
+        // all in one list, as in version 1.
+        let mut v1: Value = serde_jsonc::from_str(&saved).unwrap();
+        v1["version"] = 1.into();
+        let birth = v1["character"]["birth"].as_object_mut().unwrap();
+        let Some(Value::Array(gm_ubcs)) = birth.remove("gm_unusual_birth_circumstances") else { panic!() };
+        birth["unusual_birth_circumstances"].as_array_mut().unwrap().extend(gm_ubcs);
+        assert_eq!(saved, save(&load(&v1.to_string()).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn missing_data_is_an_error() {
+        let mut saved: Value = serde_jsonc::from_str(&save(&tester()).unwrap()).unwrap();
+        saved["character"]["race"] = "Hobgoblinoid".into();
+        assert_eq!(
+            CcholError::UnknownName { kind: "race", name: "Hobgoblinoid".into() },
+            load(&saved.to_string()).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn future_and_junk_are_errors() {
+        assert!(matches!(load(r#"{"version": 9999, "character": {}}"#), Err(CcholError::SaveFile { .. })));
+        assert!(matches!(load(r#"{"foo": "bar"}"#), Err(CcholError::SaveFile { .. })));
+        assert!(matches!(load("not json"), Err(CcholError::SaveFile { .. })));
+    }
+}
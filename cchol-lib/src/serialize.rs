@@ -9,11 +9,12 @@
 //! Other:
 //! * default_pc_save_cr_range — PC saves don't need `_cr_range` for anything, but it's present in some cases…
 //! * validate_cr_ranges — validator for those `_cr_range` which need to be without gaps or overlaps.
+//! * try_validate_cr_ranges — as per `validate_cr_ranges`, but without panicking.
 use std::ops::RangeInclusive;
 
 use serde::{Deserialize, Deserializer};
 
-use crate::{roll_range::UseRollRange, skill::native_env::NativeOf};
+use crate::{error::{CcholError, Result}, roll_range::UseRollRange, skill::native_env::NativeOf};
 
 /// Deserializer for fixed-end `_cr_range`.
 /// 
@@ -137,11 +138,27 @@ pub(crate) fn default_pc_save_cr_range() -> std::ops::RangeInclusive<i32> { 0..=
 /// 
 /// # Return
 /// Determined full-cover range.
+/// 
+/// # Panic
+/// Panics if validation fails, see [try_validate_cr_ranges] for non-panicky version.
 pub(crate) fn validate_cr_ranges(
         vecname: &str,
         cr_source: &Vec<impl UseRollRange>,
         opt_range_min: Option<i32>
 ) -> std::ops::RangeInclusive<i32> {
+    try_validate_cr_ranges(vecname, cr_source, opt_range_min)
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Validate _cr_range entries to not have gaps/overlaps.
+/// 
+/// See [validate_cr_ranges] for details.
+pub(crate) fn try_validate_cr_ranges(
+        vecname: &str,
+        cr_source: &Vec<impl UseRollRange>,
+        opt_range_min: Option<i32>
+) -> Result<std::ops::RangeInclusive<i32>> {
+    let invalid = |reason: String| CcholError::Validation { table: vecname.into(), reason };
     let mut ranges: Vec<&RangeInclusive<i32>> = cr_source
         .iter()
         .map(|c| c.roll_range())
@@ -150,14 +167,14 @@ pub(crate) fn validate_cr_ranges(
     ranges.sort_by(|a,b| a.start().cmp(b.start()));
     
     if ranges.is_empty() {
-        panic!("DATA VALIDATION: {vecname} list is empty. Cannot validate ranges.");
+        return Err(invalid("list is empty. Cannot validate ranges.".into()));
     }
 
     let min = opt_range_min.unwrap_or_else(||1);
     let start = *ranges[0].start();
 
     if start != min {
-        panic!("DATA VALIDATION: {vecname} roll table must start at {min}. Found {:#?}", ranges[0]);
+        return Err(invalid(format!("roll table must start at {min}. Found {:#?}", ranges[0])));
     }
 
     // Check for gaps/overlaps
@@ -166,7 +183,7 @@ pub(crate) fn validate_cr_ranges(
         let n = w[1];
         let expected_next_start = *c.end() + 1;
         if *n.start() != expected_next_start {
-            panic!("DATA VALIDATION: Gap or overlap in {vecname} roll table!\nFound {:#?}, followed by {:#?}", c, n);
+            return Err(invalid(format!("Gap or overlap in roll table!\nFound {:#?}, followed by {:#?}", c, n)));
         }
     }
 
@@ -174,7 +191,7 @@ pub(crate) fn validate_cr_ranges(
 
     log::debug!("{vecname} ranges successfully validated: 1..={}", *ranges.last().unwrap().end());
 
-    start..=*end
+    Ok(start..=*end)
 }
 
 /// Deserialize a single NativeOf or an array of NativeOf into Vec<NativeOf>
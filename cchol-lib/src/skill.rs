@@ -1,10 +1,10 @@
-use std::{fs, ops::{AddAssign, SubAssign}};
+use std::ops::{AddAssign, SubAssign};
 
 use lazy_static::lazy_static;
 use rpgassist::{ranking::{rank::IsRanked, Rank}, ext::IsNamed};
 use serde::{Deserialize, Serialize};
 
-use crate::ext::HasDescription;
+use crate::{data::{check, load_json, loaded}, error::{CcholError, Result}, ext::HasDescription};
 
 pub mod native_env;
 mod unusual;
@@ -15,14 +15,16 @@ pub use literacy::IsLiteracySource;
 
 pub trait IsSkill : IsRanked + IsNamed + HasDescription {}
 
-static SKILL_FILE: &'static str = "./data/skill.json";
+static SKILL_FILE: &'static str = "skill.json";
 lazy_static! {
-    pub(crate) static ref SKILLS: Vec<SkillBase> = {
-        serde_jsonc::from_str(
-            &fs::read_to_string(SKILL_FILE)
-                .expect(format!("No '{}' found!", SKILL_FILE).as_str())
-        ).expect("JSON validation error")
-    };
+    static ref SKILLS_DATA: Result<Vec<SkillBase>> = load_json(SKILL_FILE);
+    pub(crate) static ref SKILLS: &'static Vec<SkillBase> = loaded(&SKILLS_DATA);
+}
+
+/// Load [SkillBase] data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&SKILLS_DATA)?;
+    Ok(())
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -35,13 +37,27 @@ impl From<&str> for SkillBase {
     /// Get a named skill from the static storage.
     /// Be mindful about typos, as we *intentionally* panic here if the skill is *not* found.
     fn from(value: &str) -> Self {
-        SKILLS.iter()
-            .find(|s| s.name == value)
-            .expect(format!("No such skill as '{}' defined!", value).as_str())
+        Self::try_from_name(value)
+            .unwrap_or_else(|e| panic!("{e}"))
             .clone()
     }
 }
 
+impl SkillBase {
+    /// Get a named skill from the static storage, if such exists.
+    pub fn try_from_name(name: &str) -> Result<&'static Self> {
+        SKILLS.iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| CcholError::UnknownName { kind: "skill", name: name.into() })
+    }
+}
+
+impl IsNamed for SkillBase {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Skill {
     name: String,
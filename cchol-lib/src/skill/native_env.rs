@@ -5,7 +5,7 @@
 use std::fmt::Display;
 
 use rpgassist::ranking::rank::IsRanked;
-use serde::{de, Deserialize, Serialize};
+use serde::{de, ser::SerializeStruct, Deserialize, Serialize};
 
 use crate::skill::{Skill, SkillBase};
 
@@ -14,7 +14,7 @@ pub static NATIVE_ENV_WILDS_SKILL_NAME:&'static str = "Survival: Wilderness";
 pub static NATIVE_ENV_SHIP_SAILING_SKILL_NAME:&'static str = "Sailing: Ship";
 
 /// Some native environments.
-#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum NativeOf {
     Air, Underground, Aquatic,
     WaterStructure { specific: String },
@@ -84,7 +84,7 @@ impl<'de> Deserialize<'de> for NativeOf {
                 "underground" => Ok(NativeOf::Underground),
                 "aquatic" => Ok(NativeOf::Aquatic),
                 // specific Water-things…
-                "ship" => Ok(NativeOf::WaterStructure { specific: s }),
+                "ship"|"raft"|"floating village" => Ok(NativeOf::WaterStructure { specific: s }),
                 // Uh-oh …
                 _ => Err(de::Error::unknown_variant(&s, &["Urban", "Wilderness"]))
             },
@@ -93,6 +93,23 @@ impl<'de> Deserialize<'de> for NativeOf {
     }
 }
 
+impl Serialize for NativeOf {
+    /// Custom serializer for [NativeOf] fields, the mirror image of the deserializer.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer
+    {
+        match self {
+            Self::Choice { primary, secondary } => {
+                let mut choice = serializer.serialize_struct("NativeOf", 2)?;
+                choice.serialize_field("primary", primary)?;
+                choice.serialize_field("secondary", secondary)?;
+                choice.end()
+            },
+            _ => serializer.serialize_str(&self.to_string())
+        }
+    }
+}
+
 impl From<&str> for NativeOf {
     fn from(value: &str) -> Self {
         match value.to_lowercase().as_str() {
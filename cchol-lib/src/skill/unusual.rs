@@ -1,11 +1,11 @@
 //! 876: Unusual Skills
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use paste::paste;
 
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use rpgassist::ranking::Rank;
 
-use crate::skill::{Skill, SkillBase};
+use crate::{dice::DiceExt, skill::{Skill, SkillBase}};
 
 static SK_ARTISTIC: [&str; 5] = [
     /*876*/"Art: Painting",
@@ -128,7 +128,7 @@ pub fn generate_unusual_skills() -> Vec<Skill> {
         }
     };
 
-    let mut skills_map: HashMap<&'static str, Skill> = HashMap::new();
+    let mut skills_map: BTreeMap<&'static str, Skill> = BTreeMap::new();
     for name in sks {
         let rank_inc = 1;
         skills_map.entry(name)
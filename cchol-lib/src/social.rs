@@ -7,4 +7,14 @@ pub mod nobility;
 pub mod people;
     mod religion; pub use religion::Deity;
 pub mod status;
-pub mod wealth;
\ No newline at end of file
+pub mod wealth;
+use crate::error::Result;
+
+/// Load and validate all the social data.
+pub(crate) fn try_load() -> Result<()> {
+    culture::try_load()?;
+    wealth::try_load()?;
+    nobility::try_load()?;
+    religion::try_load()?;
+    people::try_load()
+}
@@ -5,10 +5,10 @@
 //!   place of birth,
 //!   unusual birth circumstances,
 //! etc.
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use serde::{Deserialize, Serialize};
 
-use crate::{Workpad, events::UnusualBirthCircumstance, modifier::{BiMod, SolMod, LegitMod}, places::birthplace::PlaceOfBirth, social::{birth_legitimacy::{IllegitimacyReason, SiblingLegit, determine_illegitimacy}, family::FamilyStructure, people::relative::RelationSubType}};
+use crate::{Workpad, dice::DiceExt, events::UnusualBirthCircumstance, modifier::{BiMod, SolMod, LegitMod}, places::birthplace::PlaceOfBirth, roll_log::{self, AppliedMod}, social::{birth_legitimacy::{IllegitimacyReason, SiblingLegit, determine_illegitimacy}, family::FamilyStructure, people::relative::RelationSubType}};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Birth {
@@ -19,8 +19,12 @@ pub struct Birth {
     family: FamilyStructure,
     birth_order: BirthOrder,
     place_of_birth: PlaceOfBirth,
+    /// Unusual birth circumstances known to the player.
     #[serde(default)]
     unusual_birth_circumstances: Vec<UnusualBirthCircumstance>,
+    /// Unusual birth circumstances kept secret from the player.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    gm_unusual_birth_circumstances: Vec<UnusualBirthCircumstance>,
 }
 
 impl BiMod for Birth {
@@ -91,9 +95,18 @@ pub enum BirthOrder {
     }
 }
 
-impl Birth {
+/// The parts of [Birth] known before the place of birth is:
+/// (il)legitimacy, family and siblings.
+#[derive(Debug, Clone)]
+pub struct Parentage {
+    illegitimacy_info: Option<(i32, IllegitimacyReason)>,
+    family: FamilyStructure,
+    siblings: Vec<SiblingLegit>,
+} impl Parentage {
+    /// Generate random [Parentage].
     pub fn random(workpad: &mut Workpad) -> Self {
         let legit = determine_illegitimacy(workpad);
+        workpad.set_illegitimacy_info(legit.clone());
         let family = FamilyStructure::random(workpad);
         let siblings = {
             let mut siblings = vec![];
@@ -116,9 +129,57 @@ impl Birth {
             }
             siblings
         };
+        Self {
+            illegitimacy_info: legit,
+            family,
+            siblings,
+        }
+    }
+
+    /// Get reason for illegitimacy, if illegitimate.
+    pub fn illegitimacy_reason(&self) -> Option<&IllegitimacyReason> {
+        self.illegitimacy_info.as_ref().map(|(_, reason)| reason)
+    }
+
+    /// Get [FamilyStructure].
+    pub fn family(&self) -> &FamilyStructure {
+        &self.family
+    }
+
+    /// Get siblings, if any.
+    pub fn siblings(&self) -> &[SiblingLegit] {
+        &self.siblings
+    }
+
+    /// Get mutable [FamilyStructure] — for tragedies and such to reshape.
+    pub(crate) fn family_mut(&mut self) -> &mut FamilyStructure {
+        &mut self.family
+    }
+
+    /// Get mutable siblings — for tragedies and such to reshape.
+    pub(crate) fn siblings_mut(&mut self) -> &mut Vec<SiblingLegit> {
+        &mut self.siblings
+    }
+}
+
+impl Birth {
+    pub fn random(workpad: &mut Workpad) -> Self {
+        let parentage = Parentage::random(workpad);
+        *workpad += parentage;
         let place_of_birth = PlaceOfBirth::random(workpad);
+        *workpad += place_of_birth.clone();
+        // unusual birth circumstances (tragedies, …) may yet reshape the parentage.
+        let (ubcs, gm_ubcs) = Self::random_unusual_birth_circumstances(&place_of_birth, workpad);
+        Self::from_parts(workpad.take_parentage(), place_of_birth, ubcs, gm_ubcs)
+    }
+
+    /// Generate random unusual birth circumstances, if any, for someone born at `place_of_birth`.
+    /// 
+    /// Gives `(player-known, GM-only)` circumstances.
+    pub fn random_unusual_birth_circumstances(place_of_birth: &PlaceOfBirth, workpad: &mut Workpad) -> (Vec<UnusualBirthCircumstance>, Vec<UnusualBirthCircumstance>) {
         // determine number of unusual birth circumstances, if any.
-        let (ubc_pc, ubc_gm) = match 1.d100() + place_of_birth.bimod() {
+        let roll = 1.d100();
+        let (ubc_pc, ubc_gm) = match roll + place_of_birth.bimod() {
             ..=60 => (0,0),
             ..=76 => (1,0),
             ..=85 => (2,0),
@@ -128,19 +189,84 @@ impl Birth {
             98 => (4,0),
             _ => { let ubc_gm = 1.d3(); (4-ubc_gm, ubc_gm)}
         };
-        let ubcs: Vec<UnusualBirthCircumstance> = (0..(ubc_pc+ubc_gm)).into_iter().map(|_| UnusualBirthCircumstance::random(workpad)).collect();
+        roll_log::record("112", roll, &[AppliedMod::BiMod(place_of_birth.bimod())], format!("{ubc_pc} unusual birth circumstance(s), {ubc_gm} for GM only"));
+        let ubcs = (0..ubc_pc).map(|_| UnusualBirthCircumstance::random(workpad)).collect();
+        if ubc_gm == 0 {
+            return (ubcs, vec![]);
+        }
+        workpad.begin_gm_secrets();
+        let gm_ubcs = (0..ubc_gm).map(|_| UnusualBirthCircumstance::random(workpad)).collect();
+        workpad.end_gm_secrets();
+        (ubcs, gm_ubcs)
+    }
 
+    /// Put together [Birth] from its (separately determined) parts.
+    /// 
+    /// [BirthOrder] gets rolled here.
+    pub fn from_parts(
+        parentage: Parentage,
+        place_of_birth: PlaceOfBirth,
+        unusual_birth_circumstances: Vec<UnusualBirthCircumstance>,
+        gm_unusual_birth_circumstances: Vec<UnusualBirthCircumstance>
+    ) -> Self {
         Self {
-            family,
-            birth_order: BirthOrder::random(siblings.len()),
-            illegitimacy_info: legit,
-            siblings,
+            birth_order: BirthOrder::random(parentage.siblings.len()),
+            illegitimacy_info: parentage.illegitimacy_info,
+            siblings: parentage.siblings,
+            family: parentage.family,
             place_of_birth,
-            unusual_birth_circumstances: ubcs,
+            unusual_birth_circumstances,
+            gm_unusual_birth_circumstances,
         }
     }
 
     pub fn starting_money_mod(&self) -> f64 {
         self.birth_order.starting_money_mod()
     }
+
+    /// Get reason for illegitimacy, if illegitimate.
+    pub fn illegitimacy_reason(&self) -> Option<&IllegitimacyReason> {
+        self.illegitimacy_info.as_ref().map(|(_, reason)| reason)
+    }
+
+    /// Get [FamilyStructure].
+    pub fn family(&self) -> &FamilyStructure {
+        &self.family
+    }
+
+    /// Get siblings, if any.
+    pub fn siblings(&self) -> &[SiblingLegit] {
+        &self.siblings
+    }
+
+    /// Get [BirthOrder].
+    pub fn birth_order(&self) -> &BirthOrder {
+        &self.birth_order
+    }
+
+    /// Get [PlaceOfBirth].
+    pub fn place_of_birth(&self) -> &PlaceOfBirth {
+        &self.place_of_birth
+    }
+
+    /// Get unusual birth circumstances known to the player, if any.
+    pub fn unusual_birth_circumstances(&self) -> &[UnusualBirthCircumstance] {
+        &self.unusual_birth_circumstances
+    }
+
+    /// Get unusual birth circumstances kept secret from the player, if any.
+    pub fn gm_unusual_birth_circumstances(&self) -> &[UnusualBirthCircumstance] {
+        &self.gm_unusual_birth_circumstances
+    }
+
+    /// Forget the GM-only unusual birth circumstances — for the player's eyes.
+    pub(crate) fn forget_gm_secrets(&mut self) {
+        self.gm_unusual_birth_circumstances.clear()
+    }
+
+    /// Put the `family` and `siblings` back as they were before GM-only circumstances reshaped them.
+    pub(crate) fn restore_family(&mut self, family: FamilyStructure, siblings: Vec<SiblingLegit>) {
+        self.family = family;
+        self.siblings = siblings;
+    }
 }
\ No newline at end of file
@@ -1,11 +1,10 @@
 //! 105: Illegitimacy Reasons
 //! 
 //! Birth (Il)legitimacy determination.
-use dicebag::DiceExt;
 use rpgassist::gender::HasGender;
 use serde::{Deserialize, Serialize};
 
-use crate::{Workpad, modifier::{CuMod, LegitMod}, social::{birth::Birth, culture::CultureCoreType, people::Relation}, traits::{HasCulture, HasCultureCoreType}};
+use crate::{Workpad, dice::DiceExt, modifier::{CuMod, LegitMod}, roll_log::{self, AppliedMod}, social::{birth::Birth, culture::CultureCoreType, people::Relation}, traits::{HasCulture, HasCultureCoreType}};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum SiblingLegit {
@@ -55,12 +54,15 @@ pub enum IllegitimacyReason {
     MotherCourtesan { father_known: bool },
 } impl IllegitimacyReason {
     pub fn random(cumod_src: &impl CuMod) -> Self {
-        match 1.d20() + cumod_src.cumod() {
+        let roll = 1.d20();
+        let reason = match roll + cumod_src.cumod() {
             ..=12 => Self::MotherCommonPrositute,
             ..=14 => Self::MotherRaped { father_known: 1.d100() < 16 },
             ..=23 => Self::MotherUnmarried { father_known: 1.d100() < 51 },
             _ => Self::MotherCourtesan { father_known: 1.d100() < 51 }
-        }
+        };
+        roll_log::record("105", roll, &[AppliedMod::CuMod(cumod_src.cumod())], format!("{reason:?}"));
+        reason
     }
 }
 
@@ -70,12 +72,17 @@ pub(crate) fn determine_illegitimacy(workpad: &mut Workpad) -> Option<(i32, Ille
     }
 
     let roll = 1.d20();
-    if (workpad.culture().core_type() == &CultureCoreType::Primitive && roll == 20)
-       || (workpad.culture().core_type() != &CultureCoreType::Primitive && roll + workpad.cumod() >= 19)
+    // primitive cultures don't apply CuMod here.
+    let primitive = workpad.culture().core_type() == &CultureCoreType::Primitive;
+    let mods = if primitive { vec![] } else { vec![AppliedMod::CuMod(workpad.cumod())] };
+    if (primitive && roll == 20)
+       || (!primitive && roll + workpad.cumod() >= 19)
     {
+        roll_log::record("104", roll, &mods, "illegitimate");
         return Some((1.d4(), IllegitimacyReason::random(workpad)));
     }
 
+    roll_log::record("104", roll, &mods, "legitimate");
     None
 }
 
@@ -1,12 +1,12 @@
 //! 102: Culture
-use std::{fmt::Display, fs::{self}};
+use std::fmt::Display;
 
 use cchol_pm::{HasCuMod, HasName, HasRollRange};
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use rpgassist::ext::IsNamed;
 
-use crate::{modifier::CuMod, roll_range::*, serialize::{deserialize_fixed_cr_range, deserialize_nativeofs_to_vec, validate_cr_ranges}, skill::{IsLiteracySource, native_env::{IsNativeOf, NativeOf}}, traits::{HasCulture, HasCultureCoreType}};
+use crate::{data::{blame_packs, check, load_json, loaded}, error::{CcholError, Result}, modifier::CuMod, roll_log, roll_range::*, serialize::{deserialize_fixed_cr_range, deserialize_nativeofs_to_vec, try_validate_cr_ranges, validate_cr_ranges}, skill::{IsLiteracySource, native_env::{IsNativeOf, NativeOf}}, traits::{HasCulture, HasCultureCoreType}};
 
 /// Trait to enforce [Culture] uncloneability outside of lazy_static initializer(s).
 trait CultureInternalClone {
@@ -14,12 +14,11 @@ trait CultureInternalClone {
 }
 
 /// FYI: all data files oughta reside within `./data/`.
-static CULTURE_FILE: &'static str = "./data/culture.json";
+static CULTURE_FILE: &'static str = "culture.json";
 lazy_static! {
     // raw json content… load/validate combo — final published product then in CULTURES below.
-    static ref __CULTURES: Vec<Culture> = serde_jsonc::from_str::<Vec<Culture>>(
-        &fs::read_to_string(CULTURE_FILE).expect(format!("Error with '{CULTURE_FILE}'?!").as_str())
-    ).expect("JSON failure");
+    static ref __CULTURES_DATA: Result<Vec<Culture>> = load_json(CULTURE_FILE);
+    static ref __CULTURES: &'static Vec<Culture> = loaded(&__CULTURES_DATA);
 
     /// Dice type to use for [Culture] [random][Culture::random]'izing.
     static ref CULTURE_RANGE: RollRange = validate_cr_ranges("CULTURES", &__CULTURES, None);
@@ -48,6 +47,16 @@ lazy_static! {
         .expect("No default max Culture defined!");
 }
 
+/// Load and validate [Culture] data.
+pub(crate) fn try_load() -> Result<()> {
+    let cultures = check(&__CULTURES_DATA)?;
+    try_validate_cr_ranges("CULTURES", cultures, None).map_err(blame_packs(CULTURE_FILE))?;
+    if !cultures.iter().any(|c| c._default_max) {
+        return Err(CcholError::Validation { table: "CULTURES".into(), reason: "No default max Culture defined!".into() })
+    }
+    Ok(())
+}
+
 /// Fixed "core types" for cultures.
 #[derive(Debug, PartialEq)]
 pub enum CultureCoreType {
@@ -164,7 +173,9 @@ impl Culture {
 
     /// Generate a random [Culture] entry.
     pub fn random() -> &'static Culture {
-        CULTURES.get_random_in_range(&*CULTURE_RANGE)
+        let (roll, culture) = CULTURES.roll_in_range(&*CULTURE_RANGE);
+        roll_log::record("102", roll, &[], culture.name());
+        culture
     }
 
     pub fn is_civilized(&self) -> bool {
@@ -182,10 +193,15 @@ impl Culture {
         if value.is_none() {
             return Self::random()
         }
-        let value = value.unwrap();
+        Self::try_from_name(value.unwrap())
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Get [Culture] by name, if such exists.
+    pub fn try_from_name(name: &str) -> Result<&'static Self> {
         CULTURES.iter()
-            .find(|c| c.name().to_lowercase() == value.to_lowercase())
-            .expect(format!("No culture called '{}' found!", value).as_str())
+            .find(|c| c.name().to_lowercase() == name.to_lowercase())
+            .ok_or_else(|| CcholError::UnknownName { kind: "culture", name: name.into() })
     }
 
     /// Get a list of skills the [Culture] provides, if any.
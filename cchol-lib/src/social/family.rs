@@ -1,13 +1,14 @@
 //! 106: Family
 
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use rpgassist::gender::{Gender, HasGender};
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
 
-use crate::{Workpad, modifier::{CuMod, SurvivalMod}, traits::{HasCulture, HasCultureCoreType}, social::{culture::CultureCoreType, people::{Relation, guardian::Guardian, relative::{CousinDistance, RelationSubType}}}};
+use crate::{Workpad, dice::{DiceExt, random_gender}, modifier::{CuMod, SurvivalMod}, roll_log::{self, AppliedMod}, traits::{HasCulture, HasCultureCoreType}, social::{culture::CultureCoreType, people::{Relation, guardian::Guardian, relative::{CousinDistance, RelationSubType}}}};
 
 /// Family structure.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
 pub enum FamilyStructure {
     Adopted (Box<FamilyStructure>),
     /// Just mom and dad (+ potential siblings).
@@ -41,15 +42,63 @@ pub enum FamilyStructure {
     /// May or may not cause a drop.
     pub fn max_wealth(&self) -> Option<&'static str> {
         match self {
-            Self::Orphanage => Some("poor"),
+            Self::Orphanage |
+            Self::Guardian(Guardian::Orphanage) => Some("poor"),
             Self::StreetKid{..} => Some("destitute"),
             _ => None
         }
     }
 
+    /// See if the given `parent` (male for father, female for mother) is part of the family.
+    pub fn has_parent(&self, parent: Gender) -> bool {
+        match self {
+            Self::MotherAndFather |
+            Self::Extended { .. } => true,
+            Self::SingleParent { gender } => *gender == parent,
+            _ => false
+        }
+    }
+
+    /// Who's left to raise the child if the given `parent` is lost.
+    pub(crate) fn without_parent(&self, parent: Gender, culture: &impl HasCultureCoreType) -> Self {
+        match self {
+            Self::MotherAndFather => Self::SingleParent { gender: if parent == Gender::Male {Gender::Female} else {Gender::Male} },
+            Self::SingleParent { gender } if *gender == parent => self.orphaned(culture),
+            _ => self.clone()
+        }
+    }
+
+    /// Who's left to raise the child if both parents are lost.
+    pub(crate) fn orphaned(&self, culture: &impl HasCultureCoreType) -> Self {
+        match self {
+            Self::Extended { grandparents, auncles, .. } => match (grandparents.first(), auncles.first()) {
+                (Some(specs), _) => Self::Grandparent { specs: specs.clone() },
+                (_, Some(specs)) => Self::AuntOrUncle { specs: specs.clone() },
+                _ => Self::Orphanage
+            },
+            Self::MotherAndFather |
+            Self::SingleParent { .. } => match culture.core_type() {
+                // the clan looks after its own.
+                CultureCoreType::Primitive |
+                CultureCoreType::Nomad => Self::Clan {
+                    primary_mother_figure: 1.d2().is_one(),
+                    primary_father_figure: 1.d2().is_one(),
+                    close_members: 3.d4()
+                },
+                _ => match 1.d6() {
+                    ..=2 => Self::Grandparents { side: random_gender() },
+                    ..=4 => Self::AuntAndUncle { side: random_gender() },
+                    _ => Self::Orphanage
+                }
+            },
+            _ => self.clone()
+        }
+    }
+
     /// Generate random [FamilyStructure].
     pub fn random(workpad: &mut Workpad) -> Self {
-        match 1.d20() + workpad.cumod() {
+        let roll = 1.d20();
+        let family = match roll + workpad.cumod() {
             ..=8 => Self::MotherAndFather,
             ..=12 => match workpad.culture().core_type() {
                 CultureCoreType::Primitive |
@@ -60,25 +109,27 @@ pub enum FamilyStructure {
                 },
                 _ => Self::Extended {
                     grandparents: (0..1.d4()).into_iter()
-                        .map(|_| RelationSubType::random(RelationSubType::Grandparent, Gender::random(), Gender::random())).collect(),
+                        .map(|_| RelationSubType::random(RelationSubType::Grandparent, random_gender(), random_gender())).collect(),
                     auncles: (0..1.d4()).into_iter()
-                        .map(|_| RelationSubType::random(RelationSubType::Auncle, Gender::random(), Gender::random())).collect(),
+                        .map(|_| RelationSubType::random(RelationSubType::Auncle, random_gender(), random_gender())).collect(),
                     cousins: (0..1.d4()).into_iter()
                         .map(|_|{
-                            Relation::Cousin { distance: CousinDistance::First, gender: Gender::random(), side: Gender::random() }
+                            Relation::Cousin { distance: CousinDistance::First, gender: random_gender(), side: random_gender() }
                         }).collect()
                 }
             },
-            13 => Self::Grandparents { side: Gender::random() },
-            14 => Self::Grandparent { specs: RelationSubType::random(RelationSubType::Grandparent, Gender::random(), Gender::random())},
-            15 => Self::AuntAndUncle { side: Gender::random() },
-            16 => Self::AuntOrUncle { specs: RelationSubType::random(RelationSubType::Auncle, Gender::random(), Gender::random())},
+            13 => Self::Grandparents { side: random_gender() },
+            14 => Self::Grandparent { specs: RelationSubType::random(RelationSubType::Grandparent, random_gender(), random_gender())},
+            15 => Self::AuntAndUncle { side: random_gender() },
+            16 => Self::AuntOrUncle { specs: RelationSubType::random(RelationSubType::Auncle, random_gender(), random_gender())},
             ..=18 => Self::SingleParent { gender: Gender::Female }/* mom, obviously */,
             19 => Self::SingleParent { gender: Gender::Male }/* dad, ditto */,
-            20 => unimplemented!("Self::Guardian(Guardian::random(workpad))"),
+            20 => Self::Guardian(Guardian::random(workpad)),
             ..=24 => Self::StreetKid { survival_mod: 1.d3() },
             _ => Self::Orphanage
-        }
+        };
+        roll_log::record("106", roll, &[AppliedMod::CuMod(workpad.cumod())], family.as_ref());
+        family
     }
 } impl SurvivalMod for FamilyStructure {
     fn survival_mod(&self) -> i32 {
@@ -1,41 +1,46 @@
 //! 103: Social Status
 //! 758: Nobles
 
-use std::fs;
 use cchol_pm::{Gendered, HasRollRange};
 use lazy_static::lazy_static;
 use rpgassist::{gender::{Gender, GenderBias, HasGender}, ext::IsNamed, serialize::serial_strings::deserialize_strings_to_vec};
 use serde::{Deserialize, Serialize};
-use dicebag::{DiceExt, InclusiveRandomRange, percentage_chance_of};
 
-use crate::{Workpad, misc::ConditionalExec, modifier::TiMod, roll_range::*, serialize::{deserialize_cr_range, deserialize_string_w_optional}, social::culture::CultureCoreType, traits::{HasCulture, HasCultureCoreType}};
+use crate::{Workpad, data::{check, load_json, loaded}, error::{CcholError, Result}, dice::{DiceExt, InclusiveRandomRange, percentage_chance_of, random_gender_biased}, misc::ConditionalExec, modifier::TiMod, roll_log, roll_range::*, serialize::{deserialize_cr_range, deserialize_string_w_optional}, social::culture::CultureCoreType, traits::{HasCulture, HasCultureCoreType}};
 
 static TIMOD_HARD_CAP: usize = 100;
-static NOBLENOTES_FILE: &'static str = "./data/nobility.json";
-static NOBLE_TITLE_PARTS_FILE: &'static str = "./data/land_titles.json";
+static NOBLENOTES_FILE: &'static str = "nobility.json";
+static NOBLE_TITLE_PARTS_FILE: &'static str = "land_titles.json";
 lazy_static! {
     // Load and parse NobleNotes …
-    static ref NOBILITYFILE: NobilityFile = serde_jsonc::from_str(
-            &fs::read_to_string(NOBLENOTES_FILE)
-                .expect(format!("No '{}' found?!", NOBLENOTES_FILE).as_str())
-        ).expect("JSON error");
+    static ref NOBILITYFILE_DATA: Result<NobilityFile> = load_json(NOBLENOTES_FILE);
+    static ref NOBILITYFILE: &'static NobilityFile = loaded(&NOBILITYFILE_DATA);
 
     // Accessor for NobilityFile.titles …
     static ref NOBLENOTES: &'static Vec<NobleNote> = &NOBILITYFILE.titles;
 
     // Determine the 'dice' to use for NobleNote matching…
-    static ref NOBLE_DICE: usize = {
-        let num_str = NOBILITYFILE.chooser.trim_start_matches('d');
-        num_str.parse::<usize>().expect(format!("Invalid 'chooser' format in '{}'", NOBLENOTES_FILE).as_str())
-    };
+    static ref NOBLE_DICE: usize = noble_dice(&NOBILITYFILE).unwrap_or_else(|e| panic!("{e}"));
 
     // Load and parse noble land titles…
-    static ref NOBLE_TITLE_PARTS: NobleTitleParts = {
-        serde_jsonc::from_str(
-            &fs::read_to_string(NOBLE_TITLE_PARTS_FILE)
-                .expect(format!("No '{}' found?!", NOBLE_TITLE_PARTS_FILE).as_str())
-        ).expect("JSON error")
-    };
+    static ref NOBLE_TITLE_PARTS_DATA: Result<NobleTitleParts> = load_json(NOBLE_TITLE_PARTS_FILE);
+    static ref NOBLE_TITLE_PARTS: &'static NobleTitleParts = loaded(&NOBLE_TITLE_PARTS_DATA);
+}
+
+/// Parse the `chooser` die (e.g. `"d100"`) of [NobilityFile].
+fn noble_dice(file: &NobilityFile) -> Result<usize> {
+    file.chooser.trim_start_matches('d').parse::<usize>()
+        .map_err(|_| CcholError::Validation {
+            table: NOBLENOTES_FILE.into(),
+            reason: format!("Invalid 'chooser' format '{}'", file.chooser)
+        })
+}
+
+/// Load and validate nobility data.
+pub(crate) fn try_load() -> Result<()> {
+    noble_dice(check(&NOBILITYFILE_DATA)?)?;
+    check(&NOBLE_TITLE_PARTS_DATA)?;
+    Ok(())
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -101,6 +106,19 @@ impl From<&'static NobleNote> for Noble {
 }
 
 impl Noble {
+    /// Get the title as it applies to given `gender`.
+    pub fn title(&self, gender: Gender) -> &str {
+        match (gender, &self.name.1) {
+            (Gender::Female, Some(title)) => title,
+            _ => &self.name.0
+        }
+    }
+
+    /// Get special/land titles, if any.
+    pub fn land_titles(&self) -> &[String] {
+        &self.land_titles
+    }
+
     /// Generate a random culture-appropriate [Noble] entry.
     pub fn random(culture_src: &impl HasCultureCoreType) -> Self {
         let r = 1.d(*NOBLE_DICE);
@@ -108,6 +126,7 @@ impl Noble {
         let note = NOBLENOTES.iter()
             .find(|n| n.culture.contains(&c) && n.roll_range().contains(&r))
             .expect(format!("No suitable NobleNote found for '{}' with roll of '{}'", c, r).as_str());
+        roll_log::record("758", r as i32, &[], note.name());
         // Refine potential 'prince' stats to be based on either parent, or treated as Archduke (basically)…
         if let Some(dfp) = &note.derive_from_parent_if {
             if 1.d100() <= *dfp {
@@ -234,7 +253,7 @@ pub struct SimpleNobleNPC {
     pub fn new_cultured(name: &str, culture_core: &impl HasCultureCoreType) -> Self {
         Self {
             name: name.to_string(),
-            gender: Gender::random_biased(GenderBias::None),
+            gender: random_gender_biased(GenderBias::None),
             nobility: Noble::random(culture_core)
         }
     }
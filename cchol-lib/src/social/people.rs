@@ -8,4 +8,13 @@ pub use relative::Relation;
 pub mod companion;
 pub use companion::Companion;
 pub mod rival;
-pub use rival::Rival;
\ No newline at end of file
+pub use rival::Rival;
+
+use crate::error::Result;
+
+/// Load and validate all the people related data.
+pub(crate) fn try_load() -> Result<()> {
+    adventurer::try_load()?;
+    govt_official::try_load()?;
+    others::try_load()
+}
@@ -1,22 +1,24 @@
-use std::fs;
-
 use cchol_pm::{Gendered, HasRollRange};
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use rpgassist::{gender::{Gender, HasGender}, ext::IsNamed};
 use serde::{Deserialize, Serialize};
 
-use crate::{racial::Race, roll_range::*, serialize::{deserialize_cr_range, validate_cr_ranges}};
+use crate::{data::{blame_packs, check, load_json, loaded}, dice::{DiceExt, random_gender}, error::Result, racial::Race, roll_range::*, serialize::{default_pc_save_cr_range, deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}};
 
-static ADVENTURER_FILE: &'static str = "./data/adventurer.json";
+static ADVENTURER_FILE: &'static str = "adventurer.json";
 lazy_static! {
-    static ref ADVENTURERS: Vec<Adventurer> = serde_jsonc::from_str(
-        &fs::read_to_string(ADVENTURER_FILE).expect(format!("Error with '{ADVENTURER_FILE}'?!").as_str())
-    ).expect("JSON error");
+    static ref ADVENTURERS_DATA: Result<Vec<Adventurer>> = load_json(ADVENTURER_FILE);
+    static ref ADVENTURERS: &'static Vec<Adventurer> = loaded(&ADVENTURERS_DATA);
 
     static ref ADVENTURERS_RANGE: RollRange = validate_cr_ranges("ADVENTURERS", &ADVENTURERS, None);
 }
 
+/// Load and validate [Adventurer] data.
+pub(crate) fn try_load() -> Result<()> {
+    try_validate_cr_ranges("ADVENTURERS", check(&ADVENTURERS_DATA)?, None).map_err(blame_packs(ADVENTURER_FILE))?;
+    Ok(())
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum AdventurerProwess {
     NotApplicable,
@@ -47,7 +49,7 @@ pub enum AdventurerProwess {
 #[derive(Debug, Deserialize, Serialize, Clone, HasRollRange, Gendered)]
 pub struct Adventurer {
     name: String,
-    #[serde(deserialize_with = "deserialize_cr_range")]
+    #[serde(deserialize_with = "deserialize_cr_range", default = "default_pc_save_cr_range", skip_serializing)]
     _cr_range: RollRange,
     #[serde(default)]// race is meaningful only for (N)PC instances.
     race: Option<String>,
@@ -65,7 +67,7 @@ pub struct Adventurer {
         } else { None };
         Self {
             race: race.map(|s| s.into()),
-            gender: Gender::random(),
+            gender: random_gender(),
             prowess: AdventurerProwess::random(),
             .. adv.clone()
         }
@@ -1,10 +1,10 @@
 use std::cmp::Ordering;
 
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}, serialize::serial_ordering};
 use serde::{Deserialize, Serialize};
 
-use crate::{racial::Race, social::{culture::Culture, people::{OtherPeople, Relation, Rival, adventurer::Adventurer}}};
+use crate::{dice::{DiceExt, random_gender}, racial::Race, social::{culture::Culture, people::{OtherPeople, Relation, Rival, adventurer::Adventurer}}};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum CompanionWho {
@@ -40,7 +40,7 @@ pub enum CompanionWho {
 } impl CompanionWho {
     fn random(culture: &Culture) -> Self {
         match 1.d10() {
-            ..=1 => Self::ChildhoodFriend { gender: Gender::random() },
+            ..=1 => Self::ChildhoodFriend { gender: random_gender() },
             2 => Self::FamilyMember(Relation::random()),
             3 => {
                 let race = Race::random_nonhuman();
@@ -48,8 +48,8 @@ pub enum CompanionWho {
                 Self::Nonhuman { race: race.name().into(), gender }},
             4 => Self::Stranger(OtherPeople::random(culture)),
             5 => Self::IntelligentInanimateObject,
-            6 => Self::SomeKid { gender: Gender::random(), age_years: 6 + 1.d6() },
-            7 => Self::Sibling { gender: Gender::random(), relative_age: if 1.d2().is_one() {Ordering::Less} else {Ordering::Greater}},
+            6 => Self::SomeKid { gender: random_gender(), age_years: 6 + 1.d6() },
+            7 => Self::Sibling { gender: random_gender(), relative_age: if 1.d2().is_one() {Ordering::Less} else {Ordering::Greater}},
             8 => Self::Adventurer(Adventurer::random()),
             9 => Self::FormerEnemyOrRival(Rival::random(culture)),
             _ => if 1.d10().is_one() { Self::GM761A } else { Self::random(culture) }
@@ -1,28 +1,26 @@
-use std::fs;
-
 use cchol_pm::{Gendered, HasRollRange};
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use rpgassist::{gender::{Gender, GenderBias, HasGender}, resolve::resolve_in_place::ResolveInPlace, serialize::serial_strings::deserialize_strings_to_vec};
 use serde::{Deserialize, Serialize};
 
-use crate::{serialize::{default_pc_save_cr_range, deserialize_fixed_cr_range, validate_cr_ranges}, roll_range::*};
+use crate::{data::{blame_packs, check, load_json, loaded}, dice::{DiceExt, resolve_gender_biased}, error::Result, serialize::{default_pc_save_cr_range, deserialize_fixed_cr_range, try_validate_cr_ranges, validate_cr_ranges}, roll_range::*};
 
 fn govt_alt_default() -> Vec<String> {vec![]}
 
-static GOVT_OFFICIALS_FILE: &'static str = "./data/people_govtoff.json";
+static GOVT_OFFICIALS_FILE: &'static str = "people_govtoff.json";
 lazy_static! {
-    static ref GOVT_OFFICIALS: Vec<GovtOfficial> = {
-        let goffs: Vec<GovtOfficial> = serde_jsonc::from_str(
-            &fs::read_to_string(GOVT_OFFICIALS_FILE)
-                .expect(format!("File error with '{GOVT_OFFICIALS_FILE}'!").as_str())
-        ).expect("JSON error");
-        goffs
-    };
+    static ref GOVT_OFFICIALS_DATA: Result<Vec<GovtOfficial>> = load_json(GOVT_OFFICIALS_FILE);
+    static ref GOVT_OFFICIALS: &'static Vec<GovtOfficial> = loaded(&GOVT_OFFICIALS_DATA);
 
     static ref GOVT_RANGE: std::ops::RangeInclusive<i32> = validate_cr_ranges("GOVT_OFFICIALS", &GOVT_OFFICIALS, None);
 }
 
+/// Load and validate [GovtOfficial] data.
+pub(crate) fn try_load() -> Result<()> {
+    try_validate_cr_ranges("GOVT_OFFICIALS", check(&GOVT_OFFICIALS_DATA)?, None).map_err(blame_packs(GOVT_OFFICIALS_FILE))?;
+    Ok(())
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, HasRollRange, Gendered)]
 pub struct GovtOfficial {
     name: String,
@@ -36,7 +34,7 @@ pub struct GovtOfficial {
 
 impl ResolveInPlace for GovtOfficial {
     fn resolve(&mut self) {
-        self.gender.resolve_biased(GenderBias::Male23);
+        resolve_gender_biased(&mut self.gender, GenderBias::Male23);
         if self.alt.len() > 0 {
             let roll = 1.d(self.alt.len() + 1);
             if roll > 1 {
@@ -1,22 +1,169 @@
 //! 754: Guardians
+use std::fmt::Display;
 
-use rpgassist::gender::{Gender, HasGender};
+use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}};
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
 
-use crate::social::{family::FamilyStructure, people::Relation};
+use crate::{Workpad, dice::{DiceExt, random_gender}, racial::{Monster, Race}, roll_log, social::{culture::CultureCoreType, family::FamilyStructure, people::{OtherPeople, Relation, adventurer::Adventurer, others::{OutcastType, WOMType}}}, traits::{HasCulture, HasCultureCoreType}};
+
+/// Institutions that take in children nobody else will.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, AsRefStr)]
+pub enum GuardianInstitution {
+    Temple,
+    Monastery,
+    School,
+    MilitaryOrder,
+    ThievesGuild,
+    MagesGuild,
+} impl GuardianInstitution {
+    /// Generate a random [GuardianInstitution] fitting for the `culture`.
+    fn random(culture: &impl HasCultureCoreType) -> Self {
+        match culture.core_type() {
+            // no schools nor guilds among barbarians.
+            CultureCoreType::Barbarian => if 1.d2() == 1 {Self::Temple} else {Self::MilitaryOrder},
+            CultureCoreType::Decadent if 1.d3() == 1 => Self::ThievesGuild,
+            _ => match 1.d6() {
+                ..=1 => Self::Temple,
+                2 => Self::Monastery,
+                3 => Self::School,
+                4 => Self::MilitaryOrder,
+                5 => Self::ThievesGuild,
+                _ => Self::MagesGuild
+            }
+        }
+    }
+}
+
+impl Display for GuardianInstitution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Temple => "the priests of a temple",
+            Self::Monastery => "the monks of a monastery",
+            Self::School => "the masters of a boarding school",
+            Self::MilitaryOrder => "the soldiers of a military order",
+            Self::ThievesGuild => "the thieves' guild",
+            Self::MagesGuild => "the mages' guild",
+        })
+    }
+}
 
 /// Guardians, guardian-like, and others…
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, AsRefStr)]
 pub enum Guardian {
     Relative(Relation),
     Orphanage,
     Adopted(Box<FamilyStructure>),
-} impl HasGender for Guardian {
+    Institution(GuardianInstitution),
+    /// Someone (or something) out of the ordinary — a hermit, a wizard, wild animals…
+    Unusual(OtherPeople),
+} impl Guardian {
+    /// Generate a random [Guardian].
+    pub fn random(workpad: &mut Workpad) -> Self {
+        let roll = 1.d20();
+        let core_type = workpad.culture().core_type();
+        let guardian = match roll {
+            ..=6 => Self::Relative(guardian_relation()),
+            ..=9 => match core_type {
+                // no orphanages out there — the clan takes the child in.
+                CultureCoreType::Primitive |
+                CultureCoreType::Nomad => Self::Adopted(Box::new(FamilyStructure::Clan {
+                    primary_mother_figure: 1.d2() == 1,
+                    primary_father_figure: 1.d2() == 1,
+                    close_members: 3.d4()
+                })),
+                _ => Self::Orphanage
+            },
+            ..=14 => Self::Adopted(Box::new(adoptive_family(workpad))),
+            ..=17 if !matches!(core_type, CultureCoreType::Primitive | CultureCoreType::Nomad) =>
+                Self::Institution(GuardianInstitution::random(core_type)),
+            _ => Self::Unusual(unusual_guardian(core_type))
+        };
+        roll_log::record("754", roll, &[], guardian.as_ref());
+        guardian
+    }
+}
+
+/// A random [Relation] fit to raise a child — no parents, spouses or children, that is.
+fn guardian_relation() -> Relation {
+    loop {
+        let relation = Relation::random();
+        let actual = match &relation {
+            Relation::ClaimsToBe(r) => r.as_ref(),
+            r => r
+        };
+        match actual {
+            Relation::Mother | Relation::Father |
+            Relation::Spouse |
+            Relation::Son | Relation::Daughter |
+            Relation::Descendant { .. } => continue,
+            _ => return relation
+        }
+    }
+}
+
+/// A random adoptive [FamilyStructure] — one that actually is a family.
+fn adoptive_family(workpad: &mut Workpad) -> FamilyStructure {
+    loop {
+        match FamilyStructure::random(workpad) {
+            FamilyStructure::Orphanage |
+            FamilyStructure::StreetKid { .. } |
+            FamilyStructure::Guardian(_) => continue,
+            family => return family
+        }
+    }
+}
+
+/// A random unusual guardian, fitting for the `culture`.
+fn unusual_guardian(culture: &impl HasCultureCoreType) -> OtherPeople {
+    match 1.d6() {
+        ..=1 => OtherPeople::WildAnimal { gender: random_gender() },
+        2 => OtherPeople::Outcast { r#type: OutcastType::Hermit, gender: random_gender() },
+        3 => OtherPeople::WielderOfMagic {
+            r#type: match culture.core_type() {
+                // the tribe's shaman, more or less.
+                CultureCoreType::Primitive |
+                CultureCoreType::Nomad => WOMType::DaringDruid,
+                _ => WOMType::random()
+            },
+            gender: random_gender() },
+        4 => OtherPeople::Nonhuman { race: Race::random_nonhuman().name().into(), gender: random_gender() },
+        5 => OtherPeople::Adventurer(Adventurer::random()),
+        _ => OtherPeople::Monster(Monster::random())
+    }
+}
+
+impl HasGender for Guardian {
     fn gender(&self) -> Gender {
         match self {
             Self::Relative(r) => r.gender(),
-            Self::Orphanage => Gender::NeverApplicable,
+            Self::Orphanage |
+            Self::Institution(_) => Gender::NeverApplicable,
             Self::Adopted(f) => f.gender(),
+            Self::Unusual(o) => o.gender(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod guardian_tests {
+    use crate::social::culture::Culture;
+
+    use super::*;
+
+    #[test]
+    fn guardians_make_sense() {
+        let mut workpad = Workpad::with_seed(754);
+        workpad += Race::random();
+        workpad += Culture::random_max_bound(workpad.race().max_culture());
+        for _ in 0..200 {
+            match Guardian::random(&mut workpad) {
+                Guardian::Relative(Relation::Mother | Relation::Father | Relation::Spouse |
+                    Relation::Son | Relation::Daughter | Relation::Descendant { .. }) => panic!("not a guardian"),
+                Guardian::Adopted(family) => assert!(!matches!(*family,
+                    FamilyStructure::Orphanage | FamilyStructure::StreetKid { .. } | FamilyStructure::Guardian(_))),
+                _ => ()
+            }
         }
     }
-}
\ No newline at end of file
+}
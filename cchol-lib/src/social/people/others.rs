@@ -1,18 +1,22 @@
 //! 750: Others
-use std::fs;
+use std::fmt::Display;
 
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use rpgassist::{gender::{Gender, GenderBias, HasGender}, ext::IsNamed};
 use serde::{Deserialize, Serialize};
 
-use crate::{misc::OccupationPerformance, racial::{Monster, Race}, social::{nobility::SimpleNobleNPC, people::{Relation, Rival, adventurer::Adventurer, govt_official::{self, GovtOfficial}}}, traits::HasCulture};
+use crate::{data::{check, load_json, loaded}, dice::{DiceExt, random_gender, random_gender_biased}, error::Result, misc::OccupationPerformance, racial::{Monster, Race}, social::{nobility::SimpleNobleNPC, people::{Relation, Rival, adventurer::Adventurer, govt_official::{self, GovtOfficial}}}, string_manip::a_or_an, traits::HasCulture};
 
-static CRIMINAL_TYPES_FILE: &'static str = "./data/criminals.json";
+static CRIMINAL_TYPES_FILE: &'static str = "criminals.json";
 lazy_static! {
-    static ref CRIMINAL_TYPES: Vec<String> = serde_jsonc::from_str(
-        &fs::read_to_string(CRIMINAL_TYPES_FILE).expect(format!("Error with '{CRIMINAL_TYPES_FILE}'?!").as_str())
-    ).expect("JSON error");
+    static ref CRIMINAL_TYPES_DATA: Result<Vec<String>> = load_json(CRIMINAL_TYPES_FILE);
+    static ref CRIMINAL_TYPES: &'static Vec<String> = loaded(&CRIMINAL_TYPES_DATA);
+}
+
+/// Load criminal type data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&CRIMINAL_TYPES_DATA)?;
+    Ok(())
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -37,7 +41,7 @@ pub enum WOMType {
     PowerfulPriest,
     WondrousWizard,
 } impl WOMType {
-    fn random() -> Self {
+    pub(crate) fn random() -> Self {
         match 1.d4() {
             ..=1 => Self::AmazingAlchemist,
             2 => Self::DaringDruid,
@@ -82,27 +86,27 @@ pub enum OtherPeople {
     pub fn random(culture: &impl HasCulture) -> Self {
         match 1.d20() {
             ..=1 => Self::GovtOfficial(govt_official::random()),
-            2 => Self::Friend { gender: Gender::random() },
-            3 => if 1.d3() == 1 {Self::Prostitute} else {Self::Outcast { r#type: OutcastType::random(), gender: Gender::random() }},
-            4 => Self::WielderOfMagic { r#type: WOMType::random(), gender: Gender::random() },
-            5 => Self::Mentor { gender: Gender::random() },
-            6 => Self::Thief { gender: Gender::random() },
+            2 => Self::Friend { gender: random_gender() },
+            3 => if 1.d3() == 1 {Self::Prostitute} else {Self::Outcast { r#type: OutcastType::random(), gender: random_gender() }},
+            4 => Self::WielderOfMagic { r#type: WOMType::random(), gender: random_gender() },
+            5 => Self::Mentor { gender: random_gender() },
+            6 => Self::Thief { gender: random_gender() },
             7 => Self::Noble { specs: SimpleNobleNPC::new("<name>") },
             8 => Self::Monster(Monster::random()),
-            9 => Self::Neighbor { gender: Gender::random() },
-            10 => Self::Lover { gender: Gender::random_biased(GenderBias::Female23) },
-            11 => Self::KnownByOccupation { occupation: unimplemented!("TODO 420,421,422,423"), gender: Gender::random() },
-            12 => Self::WildAnimal { gender: Gender::random() },
+            9 => Self::Neighbor { gender: random_gender() },
+            10 => Self::Lover { gender: random_gender_biased(GenderBias::Female23) },
+            11 => Self::KnownByOccupation { occupation: OccupationPerformance::random(culture), gender: random_gender() },
+            12 => Self::WildAnimal { gender: random_gender() },
             13 => Self::Invader,
-            14 => Self::CommonSoldier { gender: Gender::random_biased(GenderBias::Male23) },
+            14 => Self::CommonSoldier { gender: random_gender_biased(GenderBias::Male23) },
             15 => Self::Criminal {
                 r#type: CRIMINAL_TYPES[1.d(CRIMINAL_TYPES.len())-1].clone(),
-                gender: Gender::random_biased(GenderBias::Male23),
+                gender: random_gender_biased(GenderBias::Male23),
                 deg_of_involvement: 1.d20() },
             16 => Self::Adventurer(Adventurer::random()),
             17 => Self::Relative { relation: Relation::random() },
             18 => Self::Rival { specs: Rival::random(culture) },
-            19 => Self::Nonhuman { race: Race::random_nonhuman().name().into(), gender: Gender::random() },
+            19 => Self::Nonhuman { race: Race::random_nonhuman().name().into(), gender: random_gender() },
             _ => {// lets make a 2-3 combo variant
                 let mut cmb = vec![Box::new(Self::random(culture))];
                 for _ in 0..1.d2() {
@@ -114,6 +118,46 @@ pub enum OtherPeople {
     }
 }
 
+impl Display for OtherPeople {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Combined(peeps) => write!(f, "{}", peeps.iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>()
+                .join(" and ")),
+            Self::Adventurer(a) => write!(f, "an adventurer ({})", a.name().to_lowercase()),
+            Self::CommonSoldier { .. } => write!(f, "a common soldier"),
+            Self::Criminal { r#type, .. } => write!(f, "a criminal ({})", r#type.to_lowercase()),
+            Self::Friend { .. } => write!(f, "a friend"),
+            Self::GovtOfficial(_) => write!(f, "a government official"),
+            Self::Invader => write!(f, "an invader"),
+            Self::KnownByOccupation { occupation, .. } => write!(f, "{} {}", a_or_an(occupation.occupation().name()), occupation.occupation()),
+            Self::Lover { .. } => write!(f, "a lover"),
+            Self::Mentor { .. } => write!(f, "a mentor"),
+            Self::Monster(m) => write!(f, "a monster ({})", m.name().to_lowercase()),
+            Self::Neighbor { .. } => write!(f, "a neighbor"),
+            Self::Noble { specs } => write!(f, "a noble ({})", specs.nobility.name().to_lowercase()),
+            Self::Nonhuman { race, .. } => write!(f, "a nonhuman ({})", race.to_lowercase()),
+            Self::Outcast { r#type, .. } => write!(f, "{}", match r#type {
+                OutcastType::Beggar => "a beggar",
+                OutcastType::Hermit => "a hermit",
+                OutcastType::Leper => "a leper",
+            }),
+            Self::Prostitute => write!(f, "a prostitute"),
+            Self::Relative { relation } => write!(f, "the {relation}"),
+            Self::Rival { .. } => write!(f, "a rival"),
+            Self::Thief { .. } => write!(f, "a thief"),
+            Self::WielderOfMagic { r#type, .. } => write!(f, "{}", match r#type {
+                WOMType::AmazingAlchemist => "an alchemist",
+                WOMType::DaringDruid => "a druid",
+                WOMType::PowerfulPriest => "a priest",
+                WOMType::WondrousWizard => "a wizard",
+            }),
+            Self::WildAnimal { .. } => write!(f, "a wild animal"),
+        }
+    }
+}
+
 impl HasGender for OtherPeople {
     fn gender(&self) -> Gender {
         match self {
@@ -1,8 +1,9 @@
-use dicebag::DiceExt;
+use std::fmt::Display;
+
 use rpgassist::gender::{Gender, HasGender};
 use serde::{Deserialize, Serialize};
 
-use crate::Workpad;
+use crate::{Workpad, dice::{DiceExt, random_gender}};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum CousinDistance {
@@ -86,25 +87,25 @@ pub enum Relation {
 } impl Relation {
     pub fn random() -> Self {
         let rel = match 1.d(19) {
-            ..=1 => Self::Cousin { distance: CousinDistance::First, gender: Gender::random(), side: Gender::random() },
-            2 => Self::Cousin { distance: CousinDistance::Second, gender: Gender::random(), side: Gender::random() },
-            3 => Self::Cousin { distance: CousinDistance::Distant, gender: Gender::random(), side: Gender::random() },
+            ..=1 => Self::Cousin { distance: CousinDistance::First, gender: random_gender(), side: random_gender() },
+            2 => Self::Cousin { distance: CousinDistance::Second, gender: random_gender(), side: random_gender() },
+            3 => Self::Cousin { distance: CousinDistance::Distant, gender: random_gender(), side: random_gender() },
             4 => Self::Son,
             5 => Self::Daughter,
             6 => Self::Brother,
             7 => Self::Sister,
             8 => Self::Spouse,
-            9 => Self::Aunt { side: Gender::random() },
-            10 => Self::Uncle { side: Gender::random() },
-            11 => Self::GreatAunt { side: Gender::random() },
-            12 => Self::GreatUncle { side: Gender::random() },
+            9 => Self::Aunt { side: random_gender() },
+            10 => Self::Uncle { side: random_gender() },
+            11 => Self::GreatAunt { side: random_gender() },
+            12 => Self::GreatUncle { side: random_gender() },
             13 => Self::Mother,
             14 => Self::Father,
-            15 => Self::Grandmother { side: Gender::random() },
-            16 => Self::GreatGrandmother { side: Gender::random() },
-            17 => Self::Grandfather { side: Gender::random() },
-            18 => Self::GreatGrandfather { side: Gender::random() },
-            _ => Self::Descendant { generations_removed: 1.d3()+1, gender: Gender::random() }
+            15 => Self::Grandmother { side: random_gender() },
+            16 => Self::GreatGrandmother { side: random_gender() },
+            17 => Self::Grandfather { side: random_gender() },
+            18 => Self::GreatGrandfather { side: random_gender() },
+            _ => Self::Descendant { generations_removed: 1.d3()+1, gender: random_gender() }
         };
 
         if 1.d20() == 1 {
@@ -141,4 +142,52 @@ impl HasGender for Relation {
             Self::ClaimsToBe(c) => c.gender()
         }
     }
-}
\ No newline at end of file
+}
+
+/// Which side of the family, if it matters.
+fn side_of_family(side: &Gender) -> &'static str {
+    match side {
+        Gender::Male => " (father's side)",
+        Gender::Female => " (mother's side)",
+        _ => ""
+    }
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ClaimsToBe(r) => write!(f, "self-proclaimed {r}"),
+            Self::Cousin { distance, side, .. } => write!(f, "{} cousin{}", match distance {
+                CousinDistance::First => "first",
+                CousinDistance::Second => "second",
+                CousinDistance::Distant => "distant",
+            }, side_of_family(side)),
+            Self::Son => write!(f, "son"),
+            Self::Daughter => write!(f, "daughter"),
+            Self::Sister => write!(f, "sister"),
+            Self::Brother => write!(f, "brother"),
+            Self::Mother => write!(f, "mother"),
+            Self::Father => write!(f, "father"),
+            Self::Aunt { side } => write!(f, "aunt{}", side_of_family(side)),
+            Self::Uncle { side } => write!(f, "uncle{}", side_of_family(side)),
+            Self::GreatAunt { side } => write!(f, "great-aunt{}", side_of_family(side)),
+            Self::GreatUncle { side } => write!(f, "great-uncle{}", side_of_family(side)),
+            Self::Grandmother { side } => write!(f, "grandmother{}", side_of_family(side)),
+            Self::Grandfather { side } => write!(f, "grandfather{}", side_of_family(side)),
+            Self::GreatGrandmother { side } => write!(f, "great-grandmother{}", side_of_family(side)),
+            Self::GreatGrandfather { side } => write!(f, "great-grandfather{}", side_of_family(side)),
+            Self::Spouse => write!(f, "spouse"),
+            Self::Descendant { generations_removed, gender } => {
+                let child = match gender {
+                    Gender::Male => "son",
+                    Gender::Female => "daughter",
+                    _ => "child"
+                };
+                match generations_removed {
+                    ..=1 => write!(f, "{child}"),
+                    n => write!(f, "{}grand{child}", "great-".repeat(*n as usize - 2))
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,11 @@
 //! 762: Rivals
 use std::cmp::Ordering;
 
-use dicebag::{DiceExt, IsOne};
+use dicebag::IsOne;
 use rpgassist::{gender::{Gender, GenderBias, HasGender}, serialize::serial_ordering, ext::IsNamed};
 use serde::{Deserialize, Serialize};
 
-use crate::{racial::Race, social::{Deity, people::{OtherPeople, Relation}}, traits::HasCulture};
+use crate::{dice::{DiceExt, random_gender, random_gender_biased}, racial::Race, social::{Deity, people::{OtherPeople, Relation}}, traits::HasCulture};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 /// Who exactly is the rival?
@@ -45,18 +45,18 @@ pub enum RivalWho {
 } impl RivalWho {
     fn random(culture: &impl HasCulture, potential_deity: bool) -> Self {
         match 1.d10() {
-            ..=1 => Self::FormerLover { gender: Gender::random_biased(GenderBias::Female23) },
+            ..=1 => Self::FormerLover { gender: random_gender_biased(GenderBias::Female23) },
             2 => Self::FamilyMember(Relation::random()),
             3 => {
                 let race = Race::random_nonhuman();
                 let gender = race.random_gender();
                 Self::Nonhuman { race: race.name().into(), gender }},
             4 => Self::Stranger(Box::new(OtherPeople::random(culture))),
-            5 => Self::FormerFriend { gender: Gender::random() },
-            6 => Self::EnemyOfFamily { gender: Gender::random() },
-            7 => Self::ProfessionRival { gender: Gender::random() },
-            8 => Self::Sibling { gender: Gender::random(), relative_age: if 1.d2().is_one() {Ordering::Less} else {Ordering::Greater} },
-            9 => Self::Friend { gender: Gender::random() },
+            5 => Self::FormerFriend { gender: random_gender() },
+            6 => Self::EnemyOfFamily { gender: random_gender() },
+            7 => Self::ProfessionRival { gender: random_gender() },
+            8 => Self::Sibling { gender: random_gender(), relative_age: if 1.d2().is_one() {Ordering::Less} else {Ordering::Greater} },
+            9 => Self::Friend { gender: random_gender() },
             _ => if potential_deity {
                 Self::Deity(Deity::random(culture))
             } else {
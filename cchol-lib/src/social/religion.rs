@@ -1,13 +1,10 @@
 //! 864: Deities
 
-use std::fs;
-
 use cchol_pm::{Gendered, HasName, HasRollRange};
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use rpgassist::{ext::IsNamed, gender::{Gender, GenderBias, HasGender}, resolve::resolve_in_place::ResolveInPlace, serialize::serial_strings::deserialize_strings_to_vec};
 use serde::{Deserialize, Serialize};
-use crate::{modifier::CuMod, roll_range::{UseRollRange, RollRange}, serialize::deserialize_cr_range, traits::personality::{AffectsAlignment, Alignment}};
+use crate::{data::{blame_packs, check, load_json, loaded}, dice::{DiceExt, resolve_gender_biased}, error::{CcholError, Result}, modifier::CuMod, roll_range::{UseRollRange, RollRange}, serialize::{default_pc_save_cr_range, deserialize_cr_range}, social::culture::CULTURES, traits::personality::{AffectsAlignment, Alignment}};
 
 /// Deity "alignments".
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -33,11 +30,33 @@ pub enum DeityAlignment {
     }
 }
 
-static DEITY_FILE: &'static str = "./data/deity.json";
+static DEITY_FILE: &'static str = "deity.json";
 lazy_static! {
-    static ref DEITIES: Vec<Deity> = serde_jsonc::from_str(
-        &fs::read_to_string(DEITY_FILE).expect(format!("Error with '{DEITY_FILE}'?!").as_str())
-    ).expect("JSON error");
+    static ref DEITIES_DATA: Result<Vec<Deity>> = load_json(DEITY_FILE);
+    static ref DEITIES: &'static Vec<Deity> = loaded(&DEITIES_DATA);
+}
+
+/// Load and validate [Deity] data.
+pub(crate) fn try_load() -> Result<()> {
+    let deities = check(&DEITIES_DATA)?;
+    try_validate_deity_rolls(deities).map_err(blame_packs(DEITY_FILE))
+}
+
+/// Validate that every roll [Deity::random] can land on, given the cultures' CuMods, has a deity.
+///
+/// 15 and 25+ are never looked up — those shift the alignment and roll again.
+fn try_validate_deity_rolls(deities: &[Deity]) -> Result<()> {
+    let cumods = CULTURES.iter().map(|c| c.cumod());
+    let (min, max) = (cumods.clone().min().unwrap_or(0), cumods.max().unwrap_or(0));
+    for roll in (1 + min)..=(20 + max).min(24) {
+        if roll != 15 && !deities.iter().any(|d| d.roll_range().contains(&roll)) {
+            return Err(CcholError::Validation {
+                table: "DEITIES".into(),
+                reason: format!("no deity for a roll of {roll}")
+            });
+        }
+    }
+    Ok(())
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, HasRollRange, Gendered, HasName)]
@@ -51,7 +70,7 @@ pub struct Deity {
     gender_bias: GenderBias,
     #[serde(default)]
     alignment: DeityAlignment,
-    #[serde(deserialize_with = "deserialize_cr_range")]
+    #[serde(deserialize_with = "deserialize_cr_range", default = "default_pc_save_cr_range", skip_serializing)]
     _cr_range: RollRange,
 } impl Deity {
     /// Get a random [Deity].
@@ -85,7 +104,7 @@ pub struct Deity {
 
 impl ResolveInPlace for Deity {
     fn resolve(&mut self) {
-        self.gender.resolve_biased(self.gender_bias);
+        resolve_gender_biased(&mut self.gender, self.gender_bias);
 
         // resolve potential alt-name
         let roll = 1.d(self.alt.len() + 1) as i32 - 2;
@@ -93,4 +112,24 @@ impl ResolveInPlace for Deity {
             self.name = self.alt[roll as usize].clone()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod religion_tests {
+    use super::*;
+
+    #[test]
+    fn deity_file_data_integrity() {
+        try_load().unwrap();
+    }
+
+    #[test]
+    fn every_roll_needs_a_deity() {
+        let mut deities = DEITIES.to_vec();
+        deities.retain(|d| d.name != "Beast Gods");
+        assert!(matches!(try_validate_deity_rolls(&deities), Err(CcholError::Validation { .. })));
+        deities.retain(|d| d.name != "Ancestor Worship");
+        let Err(CcholError::Validation { reason, .. }) = try_validate_deity_rolls(&deities) else { panic!("Lowest rolls have no deity!") };
+        assert!(reason.ends_with("roll of -2"), "{reason}");
+    }
+}
@@ -1,10 +1,9 @@
 //! 103: Social Status
 
-use dicebag::DiceExt;
 use rpgassist::resolve::resolve_in_place::ResolveInPlace;
 use serde::{Deserialize, Serialize};
 
-use crate::{modifier::SolMod, social::{nobility::Noble, wealth::Wealth}, traits::HasCulture};
+use crate::{dice::DiceExt, modifier::SolMod, roll_log::{self, AppliedMod}, social::{nobility::Noble, wealth::Wealth}, traits::HasCulture};
 
 /// Status specs.
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -16,12 +15,21 @@ pub struct SocialStatus {
     pub fn random(culture: &impl HasCulture) -> Self {
         let mut wealth = Wealth::random(culture).clone();
         wealth.resolve();
-        let nobility = if 1.d100() + culture.cumod() >= 99 {
+        let roll = 1.d100();
+        let nobility = if roll + culture.cumod() >= 99 {
             Some(Noble::random(culture.core_type()))
         } else { None };
+        roll_log::record("103", roll, &[AppliedMod::CuMod(culture.cumod())], if nobility.is_some() {"nobility"} else {"no nobility"});
         Self { wealth, nobility }
     }
 
+    /// Create [SocialStatus] of given [`wealth`][Wealth] level, sans nobility.
+    pub fn from_wealth(wealth: &Wealth) -> Self {
+        let mut wealth = wealth.clone();
+        wealth.resolve();
+        Self { wealth, nobility: None }
+    }
+
     /// Check whether current [SocialStatus] is compatible with the given [`culture`][Culture].
     pub fn is_compatible_with(&self, culture: &impl HasCulture) -> bool {
         let noble_compatible = if let Some(n) = &self.nobility {
@@ -51,6 +59,24 @@ pub struct SocialStatus {
         }
     }
 
+    /// Drop [Wealth] down by (at most) `levels`, as far as the [`culture`][Culture] allows.
+    ///
+    /// Returns whether anything changed.
+    pub fn lower_wealth(&mut self, levels: usize, culture: &impl HasCulture) -> bool {
+        let mut lowered = false;
+        for _ in 0..levels {
+            let Some(lower) = self.wealth.lowered(culture) else { break };
+            self.wealth = lower;
+            lowered = true;
+        }
+        lowered
+    }
+
+    /// Drop [Wealth] down to `max` (e.g. "poor"), if currently above it.
+    pub fn cap_wealth(&mut self, max: &str, culture: &impl HasCulture) {
+        while self.wealth.is_above(max) && self.lower_wealth(1, culture) {}
+    }
+
     /// There goes the noble titles, buh bye…
     pub fn demote_nobility(&mut self) {
         self.nobility = None
@@ -1,15 +1,13 @@
 //! 103: Social Status (Wealth)
 
-use std::fs;
-
 use cchol_pm::{HasName, HasSolMod};
 use lazy_static::lazy_static;
 
 use rpgassist::{resolve::resolve_in_place::ResolveInPlace, ext::IsNamed, serialize::{serial_strings::deserialize_strings_to_vec, serial_uf64::deserialize as uf64_deserialize}};
 use serde::{Deserialize, Serialize};
-use dicebag::{DiceExt, DiceT};
+use dicebag::DiceT;
 
-use crate::{misc::{NO_RANGE, defaults::f64::one_f64}, modifier::{CuMod, SolMod}, roll_range::*, serialize::{deserialize_dicet, deserialize_optional_cr_range}, traits::HasCulture};
+use crate::{data::{check, load_json, loaded}, dice::DiceExt, error::{CcholError, Result}, misc::{NO_RANGE, defaults::f64::one_f64}, modifier::{CuMod, SolMod}, roll_log::{self, AppliedMod}, roll_range::*, serialize::{deserialize_dicet, deserialize_optional_cr_range}, traits::HasCulture};
 
 /// Wealth specs.
 #[derive(Debug, Deserialize, Serialize, Clone, HasName, HasSolMod)]
@@ -26,7 +24,8 @@ pub struct Wealth {
     #[serde(
         rename = "_cr_range",
         deserialize_with = "deserialize_optional_cr_range",
-        default
+        default,
+        skip_serializing
     )]  _cr_range: Option<std::ops::RangeInclusive<i32>>,
     #[serde(deserialize_with = "deserialize_strings_to_vec")]
     cultures: Vec<String>,
@@ -40,9 +39,14 @@ impl Wealth {
     /// Generate random [Wealth] level.
     pub fn random(cumod_src: &impl CuMod) -> &'static Self {
         fn mk_wealth(cumod: i32) -> &'static Wealth {
-            let roll = 1.d100() + cumod;
-            if roll == 85 { return mk_wealth(0) }
+            let raw = 1.d100();
+            let roll = raw + cumod;
+            if roll == 85 {
+                roll_log::record("103", raw, &[AppliedMod::CuMod(cumod)], "reroll without CuMod");
+                return mk_wealth(0)
+            }
             if let Some(w) = WEALTH.iter().find(|x| x.roll_range().contains(&roll)) {
+                roll_log::record("103", raw, &[AppliedMod::CuMod(cumod)], w.name());
                 return w;
             }
             mk_wealth(cumod)
@@ -51,12 +55,51 @@ impl Wealth {
         mk_wealth(cumod_src.cumod())
     }
 
+    /// Get all [Wealth] levels compatible with the given [`culture`][Culture].
+    pub fn all_compatible_with(culture: &impl HasCulture) -> Vec<&'static Self> {
+        WEALTH.iter()
+            .filter(|w| w._cr_range.is_some() && w.is_compatible_with(culture))
+            .collect()
+    }
+
     /// Check if current [Wealth] level is compatible with the given [`culture`][Culture]'s specs.
     pub fn is_compatible_with(&self, culture: &impl HasCulture) -> bool {
         self.cultures.contains(&"all".into()) ||
         self.cultures.iter().find(|name| name.to_lowercase() == culture.name().to_lowercase()).is_some()
     }
 
+    /// Get the next lower [Wealth] level compatible with the given [`culture`][Culture], if any.
+    ///
+    /// The lower level comes [resolved][ResolveInPlace] and with the same base starting money.
+    pub fn lowered(&self, culture: &impl HasCulture) -> Option<Self> {
+        let at = WEALTH.iter().position(|w| w.name == self.name)?;
+        let mut lower = WEALTH[..at].iter().rev()
+            .find(|w| w.is_compatible_with(culture))?
+            .clone();
+        lower.resolve();
+        lower.base_starting_money = self.base_starting_money;
+        Some(lower)
+    }
+
+    /// Get a named [Wealth] level from the static storage, if such exists.
+    pub fn try_from_name(name: &str) -> Result<&'static Self> {
+        WEALTH.iter()
+            .find(|w| w.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| CcholError::UnknownName { kind: "wealth", name: name.into() })
+    }
+
+    /// See if this [Wealth] level is above the named one.
+    pub fn is_above(&self, name: &str) -> bool {
+        let at = |name: &str| WEALTH.iter().position(|w| w.name.eq_ignore_ascii_case(name));
+        matches!((at(&self.name), at(name)), (Some(this), Some(that)) if this > that)
+    }
+
+    /// See if this [Wealth] level is below the named one.
+    pub fn is_below(&self, name: &str) -> bool {
+        let at = |name: &str| WEALTH.iter().position(|w| w.name.eq_ignore_ascii_case(name));
+        matches!((at(&self.name), at(name)), (Some(this), Some(that)) if this < that)
+    }
+
     /// Set base starting money.
     pub fn set_base_starting_money(&mut self, amount: u32) {
         self.base_starting_money = amount
@@ -71,7 +114,9 @@ impl Wealth {
 impl ResolveInPlace for Wealth {
     /// **NOTE:** the resolver is to be used on a **.clone()**'d [Wealth] instance.
     fn resolve(&mut self) {
-        let smod = self.survival_mod.0.d(self.survival_mod.1 as usize);
+        let (num, sides) = self.survival_mod;
+        // negative number of dice (e.g. -1d2) is rolled as a penalty.
+        let smod = num.signum() * num.abs().d(sides as usize);
         self.survival_mod = (smod, 1);
     }
 }
@@ -82,11 +127,16 @@ impl UseRollRange for Wealth {
     }
 }
 
-static WEALTH_FILE: &'static str = "./data/wealth.json";
+static WEALTH_FILE: &'static str = "wealth.json";
 lazy_static! {
-    static ref WEALTH: Vec<Wealth> = serde_jsonc::from_str(
-            &fs::read_to_string(WEALTH_FILE).expect(format!("Error with '{WEALTH_FILE}'?!").as_str())
-        ).expect("JSON error");
+    static ref WEALTH_DATA: Result<Vec<Wealth>> = load_json(WEALTH_FILE);
+    static ref WEALTH: &'static Vec<Wealth> = loaded(&WEALTH_DATA);
+}
+
+/// Load [Wealth] data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&WEALTH_DATA)?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -3,12 +3,26 @@
 use std::{collections::HashMap, ops::{AddAssign, SubAssign}};
 
 use rpgassist::stat::{Stat, StatBase};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
 
 /// [Stat] map for e.g. [PlayerCharacter] etc.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct StatMap(HashMap<StatBase, Stat>);
 
+impl Serialize for StatMap {
+    /// Serialize in a stable (alphabetical) order — [HashMap] itself has none.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort_by_key(|(base, _)| format!("{base:?}"));
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (base, stat) in entries {
+            map.serialize_entry(base, stat)?;
+        }
+        map.end()
+    }
+}
+
 impl Default for StatMap {
     /// Generate default [StatMap] with more or less sensible default values for each stat present.
     fn default() -> Self {
@@ -27,6 +41,31 @@ impl Default for StatMap {
 }
 
 impl StatMap {
+    /// Generate an all-zero [StatMap], e.g. for keeping tabs on stat *changes* rather than stats themselves.
+    pub fn zeroed() -> Self {
+        Self(HashMap::from([
+            (StatBase::Age, Stat::Age { val: 0 }),
+            (StatBase::App, Stat::App { val: 0 }),
+            (StatBase::Cha, Stat::Cha { val: 0 }),
+            (StatBase::Con, Stat::Con { val: 0 }),
+            (StatBase::Dex, Stat::Dex { val: 0 }),
+            (StatBase::Int, Stat::Int { val: 0 }),
+            (StatBase::Mag, Stat::Mag { val: 0 }),
+            (StatBase::Str, Stat::Str { val: 0 }),
+            (StatBase::Will, Stat::Will { val: 0 }),
+        ]))
+    }
+
+    /// Iterate over the [Stat]s in the map, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Stat> {
+        self.0.values()
+    }
+
+    /// Get the [Stat] of given `base`, if the map has one.
+    pub fn get(&self, base: StatBase) -> Option<&Stat> {
+        self.0.get(&base)
+    }
+
     pub fn set(&mut self, stat: Stat) -> Result<(), String> {
         if !self.0.contains_key(&stat.stat_base()) {
             return Err(format!("StatMap does not accept Stat with type '{:?}'", stat.stat_base()))
@@ -0,0 +1,435 @@
+//! Step-by-step character generation.
+//!
+//! Goes through the very same sequence as [generate_player_background][crate::generate_player_background],
+//! but one [Step] at a time: each step's result can be accepted, rerolled, or
+//! (where it makes sense) picked by hand before moving on to the next one.
+//!
+//! Accepting every first roll gives the very same character as
+//! [generate_player_background][crate::generate_player_background] would with
+//! the same seed. Reroll or pick anything and the seed no longer tells the whole
+//! story — such a character [has no seed][PlayerCharacter::seed].
+//!
+//! ```no_run
+//! use cchol_lib::{GenerationOptions, stepwise::{Step, Stepwise}};
+//!
+//! let mut stepwise = Stepwise::new(&GenerationOptions::new("Bob")).unwrap();
+//! while stepwise.step() != Step::Done {
+//!     let result = stepwise.roll();
+//!     println!("{}: {}", stepwise.step(), result.result());
+//!     stepwise.accept().unwrap();
+//! }
+//! let pc = stepwise.finish().unwrap();
+//! ```
+
+use std::fmt::Display;
+
+use rpgassist::{ext::IsNamed, gender::Gender};
+
+use crate::{GenerationOptions, Workpad, dice::random_gender, error::{CcholError, Result}, events::UnusualBirthCircumstance, misc::Occupations, pc::PlayerCharacter, places::birthplace::PlaceOfBirth, racial::{Race, race::RACES}, roll_log::{self, TableRoll}, social::{birth::{Birth, Parentage}, culture::{CULTURES, Culture}, status::SocialStatus, wealth::Wealth}, traits::HasCulture};
+
+/// Generation steps, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Race,
+    Culture,
+    SocialStatus,
+    Parentage,
+    PlaceOfBirth,
+    UnusualBirthCircumstances,
+    Occupations,
+    /// All done, [finish][Stepwise::finish] away!
+    Done,
+} impl Step {
+    fn next(self) -> Self {
+        match self {
+            Self::Race => Self::Culture,
+            Self::Culture => Self::SocialStatus,
+            Self::SocialStatus => Self::Parentage,
+            Self::Parentage => Self::PlaceOfBirth,
+            Self::PlaceOfBirth => Self::UnusualBirthCircumstances,
+            Self::UnusualBirthCircumstances => Self::Occupations,
+            Self::Occupations |
+            Self::Done => Self::Done,
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Race => "Race",
+            Self::Culture => "Culture",
+            Self::SocialStatus => "Social status",
+            Self::Parentage => "Parentage",
+            Self::PlaceOfBirth => "Place of birth",
+            Self::UnusualBirthCircumstances => "Unusual birth circumstances",
+            Self::Occupations => "Occupations",
+            Self::Done => "Done",
+        })
+    }
+}
+
+/// Result of a single step, awaiting acceptance.
+#[derive(Debug, Clone)]
+pub struct StepResult {
+    rolls: Vec<TableRoll>,
+    result: String,
+} impl StepResult {
+    /// Table rolls made, modifiers and all — none if picked by hand.
+    pub fn rolls(&self) -> &[TableRoll] {
+        &self.rolls
+    }
+
+    /// What came out of it.
+    pub fn result(&self) -> &str {
+        &self.result
+    }
+}
+
+/// Step result not yet accepted.
+enum Pending {
+    /// Race and the gender rolled (and adjusted) for it.
+    Race(&'static Race, Gender),
+    Culture(&'static Culture),
+    SocialStatus(SocialStatus),
+    Parentage(Parentage),
+    PlaceOfBirth(PlaceOfBirth),
+    /// Player-known and GM-only circumstances.
+    UnusualBirthCircumstances(Vec<UnusualBirthCircumstance>, Vec<UnusualBirthCircumstance>),
+    Occupations(Occupations),
+}
+
+impl Display for Pending {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Race(race, gender) => write!(f, "{}, {gender:?}", race.name()),
+            Self::Culture(culture) => write!(f, "{culture}"),
+            Self::SocialStatus(status) => match status.nobility() {
+                None => write!(f, "{}", status.wealth().name()),
+                Some(noble) => write!(f, "{}, {}", status.wealth().name(), noble.name()),
+            },
+            Self::Parentage(p) => write!(f, "{}, {}, {} sibling(s)",
+                if p.illegitimacy_reason().is_some() {"illegitimate"} else {"legitimate"},
+                p.family().as_ref(),
+                p.siblings().len()),
+            Self::PlaceOfBirth(place) => write!(f, "{place}"),
+            Self::UnusualBirthCircumstances(ubcs, gm_ubcs) => {
+                let names = |ubcs: &[UnusualBirthCircumstance]| ubcs.iter()
+                    .map(|u| u.as_ref())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match (ubcs.is_empty(), gm_ubcs.is_empty()) {
+                    (true, true) => write!(f, "none"),
+                    (_, true) => write!(f, "{}", names(ubcs)),
+                    (true, _) => write!(f, "GM only: {}", names(gm_ubcs)),
+                    _ => write!(f, "{}; GM only: {}", names(ubcs), names(gm_ubcs)),
+                }
+            },
+            Self::Occupations(occupations) => {
+                let parents = occupations.parents().iter()
+                    .map(|o| o.occupation().name())
+                    .collect::<Vec<_>>()
+                    .join(" and ");
+                match occupations.own() {
+                    Some(own) if parents.is_empty() => write!(f, "{}", own.occupation().name()),
+                    Some(own) => write!(f, "{}, parents: {parents}", own.occupation().name()),
+                    None => write!(f, "parents: {parents}"),
+                }
+            },
+        }
+    }
+}
+
+/// Step-by-step generator, see [module docs][self].
+pub struct Stepwise {
+    options: GenerationOptions,
+    workpad: Workpad,
+    // forced gender, if any.
+    gender: Option<Gender>,
+    step: Step,
+    // current step rolled already?
+    rolled: bool,
+    // rerolled or picked something — the seed won't reproduce the character.
+    tweaked: bool,
+    // roll log "bookmark" at the start of current step.
+    mark: usize,
+    // workpad as it was at the start of current step — some results (curses etc.) change it.
+    snapshot: Workpad,
+    pending: Option<Pending>,
+    place_of_birth: Option<PlaceOfBirth>,
+} impl Stepwise {
+    /// Start generating a character with given `options`.
+    ///
+    /// Data files get [loaded and validated][crate::data::load_data] first.
+    /// Forced race/culture, if any, are what [roll][Stepwise::roll] gives for those steps.
+    pub fn new(options: &GenerationOptions) -> Result<Self> {
+        crate::data::load_data()?;
+        if let Some(race) = &options.race {
+            Race::try_from_name(race)?;
+        }
+        if let Some(culture) = &options.culture {
+            Culture::try_from_name(culture)?;
+        }
+        let gender = options.gender.as_deref().map(crate::try_gender).transpose()?;
+        let mut workpad = match options.seed {
+            Some(seed) => Workpad::with_seed(seed),
+            None => Workpad::new()
+        };
+        workpad.set_name(&options.name);
+        Ok(Self {
+            options: options.clone(),
+            snapshot: workpad.clone(),
+            workpad,
+            gender,
+            step: Step::Race,
+            rolled: false,
+            tweaked: false,
+            mark: roll_log::mark(),
+            pending: None,
+            place_of_birth: None,
+        })
+    }
+
+    /// Get the current [Step].
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    /// (Re)roll current step.
+    ///
+    /// Does nothing (much) if all the steps are done already.
+    pub fn roll(&mut self) -> StepResult {
+        self.rewind();
+        if self.rolled {
+            self.tweaked = true;
+        }
+        self.rolled = true;
+        let pending = match self.step {
+            Step::Race => {
+                let race = Race::from(self.options.race.as_deref());
+                Pending::Race(race, race.adjust_gender(self.gender.unwrap_or_else(random_gender)))
+            },
+            Step::Culture => Pending::Culture(self.workpad.race().shift_culture_if_needed(Culture::from(self.options.culture.as_deref()))),
+            Step::SocialStatus => Pending::SocialStatus(SocialStatus::random(self.workpad.culture())),
+            Step::Parentage => Pending::Parentage(Parentage::random(&mut self.workpad)),
+            Step::PlaceOfBirth => Pending::PlaceOfBirth(PlaceOfBirth::random(&mut self.workpad)),
+            Step::UnusualBirthCircumstances => {
+                let place = self.place_of_birth.as_ref().expect("`PlaceOfBirth` not yet determined!");
+                let (ubcs, gm_ubcs) = Birth::random_unusual_birth_circumstances(place, &mut self.workpad);
+                Pending::UnusualBirthCircumstances(ubcs, gm_ubcs)
+            },
+            Step::Occupations => Pending::Occupations(Occupations::random(&mut self.workpad)),
+            Step::Done => return StepResult { rolls: vec![], result: "all done".into() }
+        };
+        self.settle(pending)
+    }
+
+    /// Get the entries to hand-pick from for current step, if hand-picking is an option.
+    pub fn choices(&self) -> Vec<String> {
+        match self.step {
+            Step::Race => RACES.iter().map(|r| r.name().to_string()).collect(),
+            Step::Culture => distinct_cultures().iter().map(|c| c.name().to_string()).collect(),
+            Step::SocialStatus => Wealth::all_compatible_with(self.workpad.culture()).iter().map(|w| w.name().to_string()).collect(),
+            Step::PlaceOfBirth => PlaceOfBirth::choices(),
+            _ => vec![]
+        }
+    }
+
+    /// Hand-pick current step's result by `index` into [choices][Stepwise::choices].
+    pub fn pick(&mut self, index: usize) -> Result<StepResult> {
+        let unknown = || CcholError::UnknownName { kind: "choice", name: index.saturating_add(1).to_string() };
+        // a failed pick leaves the previous result, and whatever it did, be.
+        if index >= self.choices().len() {
+            return Err(unknown());
+        }
+        self.rewind();
+        let pending = match self.step {
+            Step::Race => {
+                let race = RACES.get(index).ok_or_else(unknown)?;
+                Pending::Race(race, race.adjust_gender(self.gender.unwrap_or_else(random_gender)))
+            },
+            // picked or not, the race has its say about culture.
+            Step::Culture => Pending::Culture(self.workpad.race().shift_culture_if_needed(distinct_cultures().get(index).ok_or_else(unknown)?)),
+            Step::SocialStatus => Pending::SocialStatus(SocialStatus::from_wealth(
+                Wealth::all_compatible_with(self.workpad.culture()).get(index).ok_or_else(unknown)?)),
+            Step::PlaceOfBirth => Pending::PlaceOfBirth(PlaceOfBirth::pick(index, &mut self.workpad).ok_or_else(unknown)?),
+            _ => return Err(unknown())
+        };
+        self.tweaked = true;
+        Ok(self.settle(pending))
+    }
+
+    /// Undo whatever the current step's previous (re)roll did.
+    fn rewind(&mut self) {
+        roll_log::rewind(self.mark);
+        self.workpad = self.snapshot.clone();
+    }
+
+    /// Take `pending` as current step's result.
+    fn settle(&mut self, pending: Pending) -> StepResult {
+        let result = StepResult { rolls: roll_log::since(self.mark), result: pending.to_string() };
+        self.pending = Some(pending);
+        result
+    }
+
+    /// Accept current step's result and move on to the next step.
+    pub fn accept(&mut self) -> Result<()> {
+        let pending = self.pending.take().ok_or(CcholError::OutOfSequence { what: "step result" })?;
+        match pending {
+            Pending::Race(race, gender) => {
+                self.workpad += race;
+                self.workpad += gender;
+            },
+            Pending::Culture(culture) => self.workpad += culture,
+            Pending::SocialStatus(status) => self.workpad += status,
+            // illegitimacy is already noted in the workpad by Parentage::random.
+            Pending::Parentage(parentage) => self.workpad += parentage,
+            Pending::PlaceOfBirth(place) => {
+                self.workpad += place.clone();
+                self.place_of_birth = Some(place);
+            },
+            Pending::UnusualBirthCircumstances(ubcs, gm_ubcs) => {
+                let place = self.place_of_birth.take().expect("`PlaceOfBirth` not yet determined!");
+                // tragedies and such may have reshaped the parentage since it was accepted.
+                let parentage = self.workpad.take_parentage();
+                self.workpad += Birth::from_parts(parentage, place, ubcs, gm_ubcs);
+            },
+            Pending::Occupations(occupations) => self.workpad += occupations,
+        }
+        self.step = self.step.next();
+        self.rolled = false;
+        self.mark = roll_log::mark();
+        self.snapshot = self.workpad.clone();
+        Ok(())
+    }
+
+    /// Finish up — all the steps have to be done by now.
+    pub fn finish(mut self) -> Result<PlayerCharacter> {
+        if self.step != Step::Done {
+            return Err(CcholError::OutOfSequence { what: "Birth" });
+        }
+        let mut pc = PlayerCharacter::create(&mut self.workpad);
+        if !self.options.roll_log {
+            pc.forget_roll_log();
+        }
+        if self.tweaked {
+            pc.forget_seed();
+        }
+        Ok(pc)
+    }
+}
+
+/// [CULTURES] without the repeats it has for cultures with a choice of native environment.
+fn distinct_cultures() -> Vec<&'static Culture> {
+    let mut cultures: Vec<&'static Culture> = vec![];
+    for culture in CULTURES.iter() {
+        if !cultures.iter().any(|c| c.name() == culture.name()) {
+            cultures.push(culture);
+        }
+    }
+    cultures
+}
+
+#[cfg(test)]
+mod stepwise_tests {
+    use super::*;
+
+    #[test]
+    fn accept_all_the_way() {
+        let mut options = GenerationOptions::new("Tester");
+        options.with_seed(42).with_roll_log();
+        let mut stepwise = Stepwise::new(&options).unwrap();
+        assert!(stepwise.accept().is_err());
+        while stepwise.step() != Step::Done {
+            stepwise.roll();
+            stepwise.accept().unwrap();
+        }
+        let pc = stepwise.finish().unwrap();
+        assert_eq!("Tester", pc.name());
+        assert!(!pc.roll_log().is_empty());
+        assert_eq!(Some(42), pc.seed());
+    }
+
+    #[test]
+    fn accepting_everything_equals_generate() {
+        for seed in 0..50 {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed).with_roll_log();
+            let mut stepwise = Stepwise::new(&options).unwrap();
+            while stepwise.step() != Step::Done {
+                stepwise.roll();
+                stepwise.accept().unwrap();
+            }
+            let stepped = serde_jsonc::to_value(stepwise.finish().unwrap()).unwrap();
+            let generated = serde_jsonc::to_value(crate::generate_player_background(&options)).unwrap();
+            assert_eq!(generated, stepped, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn tweaked_character_has_no_seed() {
+        let mut options = GenerationOptions::new("Tester");
+        options.with_seed(42);
+        for tweak in [|s: &mut Stepwise| { s.roll(); }, |s: &mut Stepwise| { s.pick(0).unwrap(); }] {
+            let mut stepwise = Stepwise::new(&options).unwrap();
+            stepwise.roll();
+            tweak(&mut stepwise);
+            while stepwise.step() != Step::Done {
+                stepwise.accept().unwrap();
+                stepwise.roll();
+            }
+            assert_eq!(None, stepwise.finish().unwrap().seed());
+        }
+    }
+
+    #[test]
+    fn reroll_and_pick() {
+        let mut stepwise = Stepwise::new(&GenerationOptions::new("Tester")).unwrap();
+        stepwise.roll();
+        let first = stepwise.roll();
+        // rerolls replace, not pile up, the step's rolls.
+        assert_eq!(1, first.rolls().len());
+        let picked = stepwise.pick(0).unwrap();
+        assert!(picked.rolls().is_empty());
+        assert!(picked.result().starts_with(&stepwise.choices()[0]));
+        assert!(stepwise.pick(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn culture_choices_are_distinct() {
+        let mut stepwise = Stepwise::new(&GenerationOptions::new("Tester")).unwrap();
+        stepwise.roll();
+        stepwise.accept().unwrap();
+        let mut choices = stepwise.choices();
+        let count = choices.len();
+        choices.sort();
+        choices.dedup();
+        assert_eq!(count, choices.len());
+        // the race may shift the culture, but whatever comes out is on the list.
+        let picked = stepwise.pick(count - 1).unwrap();
+        assert!(choices.iter().any(|c| c == picked.result()));
+    }
+
+    #[test]
+    fn failed_pick_changes_nothing() {
+        let fingerprint = |s: &Stepwise| format!("{:?}|{:?}|{:?}|{:?}|{:?}",
+            s.pending.as_ref().map(|p| p.to_string()),
+            serde_jsonc::to_value(s.workpad.get_statmap()).unwrap(),
+            serde_jsonc::to_value(s.workpad.get_skills()).unwrap(),
+            serde_jsonc::to_value(s.workpad.get_possessions()).unwrap(),
+            (s.workpad.clone().parentage_mut().map(|p| format!("{p:?}")), roll_log::since(s.mark)));
+        for seed in 0..50 {
+            let mut options = GenerationOptions::new("Tester");
+            options.with_seed(seed);
+            let mut stepwise = Stepwise::new(&options).unwrap();
+            while stepwise.step() != Step::Done {
+                stepwise.roll();
+                let before = fingerprint(&stepwise);
+                // steps with no choices at all fail any pick.
+                assert!(stepwise.pick(stepwise.choices().len()).is_err());
+                assert_eq!(before, fingerprint(&stepwise), "seed {seed}, step {}", stepwise.step());
+                stepwise.accept().unwrap();
+            }
+        }
+    }
+}
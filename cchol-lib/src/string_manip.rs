@@ -1,4 +1,8 @@
+mod article;
+pub(crate) use article::a_or_an;
 mod hook_resolve;
-pub(crate) use hook_resolve::resolve_name_hooks;
+pub(crate) use hook_resolve::{resolve_name_hooks, try_check_name_hooks};
 mod pluralize;
-pub(crate) use pluralize::{pluralize, pluralize_gendered};
\ No newline at end of file
+pub(crate) use pluralize::{pluralize, pluralize_gendered};
+mod pronoun;
+pub(crate) use pronoun::Pronouns;
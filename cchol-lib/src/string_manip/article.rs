@@ -0,0 +1,7 @@
+/// "a" or "an", as fits `word`.
+pub fn a_or_an(word: &str) -> &'static str {
+    match word.chars().next().map(|c| c.to_ascii_lowercase()) {
+        Some('a'|'e'|'i'|'o'|'u') => "an",
+        _ => "a"
+    }
+}
@@ -2,7 +2,7 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use rpgassist::ext::IsNamed;
 
-use crate::{Workpad, racial::{Monster, Race}, social::{Deity, nobility::Noble}, string_manip::pluralize::Pluralizer, traits::HasCulture};
+use crate::{Workpad, error::{CcholError, Result}, racial::{Monster, Race}, social::{Deity, nobility::Noble}, string_manip::pluralize::Pluralizer, traits::HasCulture};
 
 lazy_static! {
     static ref RX_TAG_NAME: Regex = Regex::new(r"<(\w+)>").expect("Regex made a booboo with '<X>' for some reason!");
@@ -12,8 +12,23 @@ lazy_static! {
 }
 
 /// Resolve a variety of "hooks" into contained names.
+/// 
+/// **FYI:** we *intentionally* panic if an unknown hook is encountered —
+/// [load_data][crate::data::load_data] checks the data files for those beforehand.
 pub(crate) fn resolve_name_hooks(candidate_name: &str, workpad: &Workpad) -> String {
+    try_resolve_name_hooks(candidate_name, workpad)
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Resolve a variety of "hooks" into contained names, if all the hooks are known ones.
+pub(crate) fn try_resolve_name_hooks(candidate_name: &str, workpad: &Workpad) -> Result<String> {
     let mut resolved = candidate_name.to_string();
+    // First unknown hook encountered, if any…
+    let mut failure: Option<CcholError> = None;
+    let mut unknown = |hook: String| {
+        failure.get_or_insert(CcholError::UnknownName { kind: "name hook", name: hook });
+        String::new()
+    };
     
     // <Foobar> tags first…
     resolved = RX_TAG_NAME.replace_all(&resolved, |caps:&regex::Captures|{
@@ -22,7 +37,7 @@ pub(crate) fn resolve_name_hooks(candidate_name: &str, workpad: &Workpad) -> Str
             "Deity" => Deity::random(workpad).name().to_string(),
             "Monster" => Monster::random().name().to_string(),
             "Nonhuman" => Race::random_nonhuman().name().to_string(),
-            _ => unimplemented!("Unspecified tag '{tag}' encountered!")
+            _ => unknown(format!("<{tag}>"))
         }
     }).into_owned();
 
@@ -42,7 +57,7 @@ pub(crate) fn resolve_name_hooks(candidate_name: &str, workpad: &Workpad) -> Str
                     resolved.clone()
                 }
             },
-            unknown => unimplemented!("`Tag-w/Range` encountered a tag ('{unknown}') with which we have no clue what to do with…")
+            _ => unknown(format!("<{tag}:{scope_start}..{scope_end}>"))
         }
     }).into_owned();
 
@@ -55,7 +70,7 @@ pub(crate) fn resolve_name_hooks(candidate_name: &str, workpad: &Workpad) -> Str
         match specifier {
             "plural" => match tag {
                 "Nonhuman" => return Race::random_nonhuman().name().pluralize(),
-                _ => unimplemented!("I don't know how to fetch or pluralize variant(s) of '{tag}'")
+                _ => return unknown(format!("<{tag}:{specifier}>"))
             },
             _ => {/* fall through */}
         }
@@ -71,9 +86,71 @@ pub(crate) fn resolve_name_hooks(candidate_name: &str, workpad: &Workpad) -> Str
                     resolved.clone()
                 }
             },
-            _ => unimplemented!("No '{tag}':'{specifier}' combo implemented…")
+            _ => unknown(format!("<{tag}:{specifier}>"))
         }
     }).into_owned();
 
-    resolved
-}
\ No newline at end of file
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(resolved)
+    }
+}
+/// Check that all the hooks in `candidate_name`, if any, are ones [try_resolve_name_hooks] knows of.
+///
+/// Nothing gets resolved (nor rolled) here — this is for validating data files with.
+pub(crate) fn try_check_name_hooks(table: &str, candidate_name: &str) -> Result<()> {
+    let unknown = |hook: &str| Err(CcholError::Validation {
+        table: table.into(),
+        reason: format!("unknown name hook '{hook}' in '{candidate_name}'")
+    });
+    for caps in RX_TAG_NAME.captures_iter(candidate_name) {
+        if !matches!(&caps[1], "Deity" | "Monster" | "Nonhuman") {
+            return unknown(&caps[0]);
+        }
+    }
+    for caps in RX_TAG_WITH_RANGE.captures_iter(candidate_name) {
+        if &caps[1] != "Noble" {
+            return unknown(&caps[0]);
+        }
+    }
+    for caps in RX_TAG_WITH_SPECIFIER.captures_iter(candidate_name) {
+        match (&caps[1], &caps[2]) {
+            ("Nonhuman", "plural") => (),
+            (_, "plural") => return unknown(&caps[0]),
+            ("Noble", _) => (),
+            _ => return unknown(&caps[0])
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod hook_resolve_tests {
+    use super::*;
+
+    #[test]
+    fn unknown_hook_is_an_error() {
+        let workpad = Workpad::new();
+        assert_eq!("Plain name", try_resolve_name_hooks("Plain name", &workpad).unwrap());
+        assert_eq!(
+            CcholError::UnknownName { kind: "name hook", name: "<Bogus>".into() },
+            try_resolve_name_hooks("The <Bogus> Place", &workpad).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn check_agrees_with_resolve() {
+        let workpad = Workpad::new();
+        for name in ["Plain name", "Lair of <Monster>", "Among <Nonhuman:plural>", "The <Bogus> Place", "<Nonhuman:bogus>", "<Monster:plural>", "<Bogus:Baron..Duke>"] {
+            assert_eq!(try_resolve_name_hooks(name, &workpad).is_ok(), try_check_name_hooks("TEST", name).is_ok(), "{name}");
+        }
+        // these need a culture to resolve, but are known ones nonetheless.
+        for name in ["Temple of <Deity>", "Court of <Noble:Baron..Duke>", "Seat of <Noble:Count>"] {
+            assert_eq!(Ok(()), try_check_name_hooks("TEST", name));
+        }
+        assert_eq!(
+            CcholError::Validation { table: "TEST".into(), reason: "unknown name hook '<Bogus>' in 'The <Bogus> Place'".into() },
+            try_check_name_hooks("TEST", "The <Bogus> Place").unwrap_err()
+        );
+    }
+}
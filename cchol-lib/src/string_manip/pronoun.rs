@@ -0,0 +1,44 @@
+//! Pronouns (and the verbs agreeing with them).
+
+use rpgassist::gender::Gender;
+
+/// Pronouns for some [Gender] — "they" for when there's no (known) gender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Pronouns {
+    /// he/she/they
+    pub subject: &'static str,
+    /// him/her/them
+    pub object: &'static str,
+    /// his/her/their
+    pub possessive: &'static str,
+    /// himself/herself/themselves
+    pub reflexive: &'static str,
+    plural: bool,
+}
+
+impl Pronouns {
+    /// is/are
+    pub fn is(&self) -> &'static str {
+        if self.plural {"are"} else {"is"}
+    }
+
+    /// was/were
+    pub fn was(&self) -> &'static str {
+        if self.plural {"were"} else {"was"}
+    }
+
+    /// has/have
+    pub fn has(&self) -> &'static str {
+        if self.plural {"have"} else {"has"}
+    }
+}
+
+impl From<Gender> for Pronouns {
+    fn from(gender: Gender) -> Self {
+        match gender {
+            Gender::Male => Self { subject: "he", object: "him", possessive: "his", reflexive: "himself", plural: false },
+            Gender::Female => Self { subject: "she", object: "her", possessive: "her", reflexive: "herself", plural: false },
+            _ => Self { subject: "they", object: "them", possessive: "their", reflexive: "themselves", plural: true },
+        }
+    }
+}
@@ -4,12 +4,11 @@
 //! 648: Darkside Traits
 
 use std::fmt::Display;
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
 
-use dicebag::DiceExt;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
-use crate::ext::IsZero;
+use crate::{data::{check, load_json, loaded}, dice::DiceExt, error::Result, ext::IsZero};
 use rpgassist::ext::IsNamed;
 
 pub mod personality_al;
@@ -28,14 +27,9 @@ pub use personality_sd::sexual_disorder;
 
 pub(crate) type TraitVec = Vec<PersonalityTrait>;
 
-static PERSONALITY_FILE: &'static str = "./data/personality.json";
+static PERSONALITY_FILE: &'static str = "personality.json";
 lazy_static! {
-    static ref PERSONALITY_TRAITS: HashMap<String, DLNTrait> = {
-        let traits_data: PersonalityTraits = serde_jsonc::from_str(
-            &fs::read_to_string(PERSONALITY_FILE)
-                .expect(format!("No '{}' found?!", PERSONALITY_FILE).as_str())
-        ).expect("JSON error");
-
+    static ref PERSONALITY_TRAITS_DATA: Result<HashMap<String, DLNTrait>> = load_json(PERSONALITY_FILE).map(|traits_data: PersonalityTraits| {
         let mut allmap = HashMap::new();
 
         for mut trait_ in traits_data.lightside_traits {
@@ -54,25 +48,34 @@ lazy_static! {
         }
 
         allmap
-    };
+    });
+    static ref PERSONALITY_TRAITS: &'static HashMap<String, DLNTrait> = loaded(&PERSONALITY_TRAITS_DATA);
 
-    static ref LIGHTSIDE_TRAITS: Vec<&'static DLNTrait> = {
-        PERSONALITY_TRAITS.values()
-            .filter(|t| t.classification == Alignment::L)
-            .collect()
-    };
+    static ref LIGHTSIDE_TRAITS: Vec<&'static DLNTrait> = traits_of(Alignment::L);
 
-    static ref DARKSIDE_TRAITS: Vec<&'static DLNTrait> = {
-        PERSONALITY_TRAITS.values()
-            .filter(|t| t.classification == Alignment::D)
-            .collect()
-    };
+    static ref DARKSIDE_TRAITS: Vec<&'static DLNTrait> = traits_of(Alignment::D);
 
-    static ref NEUTRAL_TRAITS: Vec<&'static DLNTrait> = {
-        PERSONALITY_TRAITS.values()
-            .filter(|t| t.classification == Alignment::N)
-            .collect()
-    };
+    static ref NEUTRAL_TRAITS: Vec<&'static DLNTrait> = traits_of(Alignment::N);
+}
+
+/// Load personality trait data.
+pub(crate) fn try_load() -> Result<()> {
+    check(&PERSONALITY_TRAITS_DATA)?;
+    allergies::try_load()?;
+    behavior_tag::try_load()?;
+    phobias::try_load()?;
+    Ok(())
+}
+
+/// Get all traits of given `alignment`, sorted by name.
+/// 
+/// Sorted, as [HashMap] order would otherwise randomize seeded rolls.
+fn traits_of(alignment: Alignment) -> Vec<&'static DLNTrait> {
+    let mut traits: Vec<_> = PERSONALITY_TRAITS.values()
+        .filter(|t| t.classification == alignment)
+        .collect();
+    traits.sort_by(|a, b| a.name.cmp(&b.name));
+    traits
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -109,7 +112,7 @@ impl Default for Alignment {
 }
 
 /// Various degrees/strength of personality traits.
-#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum TraitStrength {
     Trivial,
     Weak,
@@ -126,6 +129,19 @@ impl Default for TraitStrength {
     }
 }
 
+impl Display for TraitStrength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Trivial => "trivial",
+            Self::Weak => "weak",
+            Self::Average => "average",
+            Self::Strong => "strong",
+            Self::Driving => "driving",
+            Self::Obsessive => "obsessive",
+        })
+    }
+}
+
 impl TraitStrength {
     /// Generate random personality trait strength.
     pub fn random() -> Self {
@@ -313,6 +329,26 @@ impl TraitRollResult {
 mod personality_tests {
     use super::*;
 
+    /// Roll `rolls` traits with `random`, banning whatever got rolled so far, and check that none
+    /// of them repeat — then, if given, check that banning `everything` leaves nothing to roll.
+    ///
+    /// Gives the rolled traits for any table specific checks.
+    pub(crate) fn assert_respects_bans(random: fn(&TraitVec) -> TraitRollResult, rolls: usize, everything: Option<TraitVec>) -> TraitVec {
+        let mut bans: TraitVec = vec![];
+        for _ in 0..rolls {
+            random(&bans).apply(&mut bans);
+        }
+        let mut names: Vec<&str> = bans.iter().map(|t| t.name()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(bans.len(), names.len());
+
+        if let Some(everything) = everything {
+            assert!(matches!(random(&everything), TraitRollResult::NoMatch));
+        }
+        bans
+    }
+
     #[test]
     fn bans() {
         let mut bans = vec![];
@@ -1,29 +1,179 @@
 pub mod allergies {
     use std::fmt::Display;
 
+    use cchol_pm::HasRollRange;
+    use lazy_static::lazy_static;
     use serde::{Deserialize, Serialize};
     use rpgassist::ext::IsNamed;
 
-    use crate::{traits::personality::{TraitRollResult, TraitVec}};
+    use crate::{data::{blame_packs, check, load_json, loaded}, dice::{DiceExt, RandomOf}, error::{CcholError, Result}, ext::IsZero, roll_log, roll_range::*, serialize::{deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}, traits::personality::{PersonalityTrait, TraitRollResult, TraitVec, exotic_trait::ExoticTrait}};
 
+    static ALLERGY_FILE: &'static str = "personality_al.json";
+    lazy_static! {
+        static ref ALLERGY_FILE_DATA: Result<AllergyFile> = load_json(ALLERGY_FILE);
+        static ref ALLERGY_DATA: &'static AllergyFile = loaded(&ALLERGY_FILE_DATA);
+
+        static ref ALLERGENS_RANGE: RollRange = validate_cr_ranges("ALLERGENS", &ALLERGY_DATA.allergens, None);
+    }
+
+    /// Load and validate [Allergy] data.
+    pub(crate) fn try_load() -> Result<()> {
+        let data = check(&ALLERGY_FILE_DATA)?;
+        try_validate_cr_ranges("ALLERGENS", &data.allergens, None).map_err(blame_packs(ALLERGY_FILE))?;
+        for severity in [AllergySeverity::Mild, AllergySeverity::Moderate, AllergySeverity::Severe, AllergySeverity::Deadly] {
+            if !data.reactions.iter().any(|r| r.severity == severity) {
+                return Err(blame_packs(ALLERGY_FILE)(CcholError::Validation {
+                    table: ALLERGY_FILE.into(),
+                    reason: format!("No reactions for '{severity}' allergies")
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    /// JSON deserialize struct.
+    #[derive(Debug, Deserialize)]
+    struct AllergyFile {
+        allergens: Vec<AllergenNote>,
+        reactions: Vec<ReactionNote>,
+    }
+
+    /// Allergen table entry.
+    #[derive(Debug, Deserialize, Clone, HasRollRange)]
+    struct AllergenNote {
+        name: String,
+        #[serde(deserialize_with = "deserialize_cr_range")]
+        _cr_range: RollRange,
+    }
+
+    /// Reaction table entry.
+    #[derive(Debug, Deserialize, Clone)]
+    struct ReactionNote {
+        severity: AllergySeverity,
+        reaction: String,
+    }
+
+    /// How badly exposure to the allergen goes.
+    #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+    pub enum AllergySeverity {
+        Mild,
+        Moderate,
+        Severe,
+        Deadly,
+    } impl AllergySeverity {
+        /// Generate random [AllergySeverity].
+        pub fn random() -> Self {
+            match 1.d10() {
+                ..=4 => Self::Mild,
+                ..=7 => Self::Moderate,
+                ..=9 => Self::Severe,
+                _ => Self::Deadly
+            }
+        }
+    }
+
+    impl Display for AllergySeverity {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", match self {
+                Self::Mild => "mild",
+                Self::Moderate => "moderate",
+                Self::Severe => "severe",
+                Self::Deadly => "deadly",
+            })
+        }
+    }
+
+    /// An allergy — to what, how bad, and what happens upon exposure.
     #[derive(Debug, Deserialize, Serialize, Clone)]
-    pub enum Allergy {
+    pub struct Allergy {
+        allergen: String,
+        severity: AllergySeverity,
+        reaction: String,
+    } impl Allergy {
+        /// Get the [AllergySeverity].
+        pub fn severity(&self) -> AllergySeverity {
+            self.severity
+        }
 
+        /// What exposure to the allergen does.
+        pub fn reaction(&self) -> &str {
+            &self.reaction
+        }
     }
 
+    /// 649C: Generate a random allergy — to something not already in `bans`.
     pub fn random(bans: &TraitVec) -> TraitRollResult {
-        unimplemented!()
+        let mut bail_out_at_zero = 100;
+        loop {
+            bail_out_at_zero -= 1;
+
+            let (roll, allergen) = ALLERGY_DATA.allergens.roll_in_range(&ALLERGENS_RANGE);
+            if !bans.iter().any(|t| t.name() == allergen.name) {
+                roll_log::record("649C", roll, &[], &allergen.name);
+                let severity = AllergySeverity::random();
+                let reactions: Vec<&ReactionNote> = ALLERGY_DATA.reactions.iter()
+                    .filter(|r| r.severity == severity)
+                    .collect();
+                return TraitRollResult::Add(Allergy {
+                    allergen: allergen.name.clone(),
+                    severity,
+                    reaction: reactions.random_of().reaction.clone(),
+                }.into());
+            }
+
+            if bail_out_at_zero.is_zero() {
+                log::error!("All allergy options exhausted!");
+                return TraitRollResult::NoMatch;
+            }
+        }
     }
 
     impl IsNamed for Allergy {
         fn name(&self) -> &str {
-            unimplemented!()
+            &self.allergen
         }
     }
 
     impl Display for Allergy {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            unimplemented!()
+            write!(f, "{} allergy to {} ({})", self.severity, self.allergen, self.reaction)
+        }
+    }
+
+    impl From<Allergy> for PersonalityTrait {
+        fn from(value: Allergy) -> Self {
+            PersonalityTrait::EX(ExoticTrait::AL(value))
+        }
+    }
+
+    #[cfg(test)]
+    mod allergy_tests {
+        use crate::traits::personality::personality_tests::assert_respects_bans;
+
+        use super::*;
+
+        #[test]
+        fn allergy_file_data_integrity() {
+            try_load().unwrap();
+        }
+
+        #[test]
+        fn allergies_respect_bans() {
+            let everything = ALLERGY_DATA.allergens.iter()
+                .map(|a| Allergy { allergen: a.name.clone(), severity: AllergySeverity::Mild, reaction: String::new() }.into())
+                .collect();
+            assert_respects_bans(random, 10, Some(everything));
+        }
+
+        #[test]
+        fn reaction_fits_severity() {
+            let mark = roll_log::mark();
+            for allergy in assert_respects_bans(random, 10, None) {
+                let PersonalityTrait::EX(ExoticTrait::AL(allergy)) = allergy else { panic!("Not an allergy: {allergy:?}") };
+                assert!(ALLERGY_DATA.reactions.iter().any(|r| r.severity == allergy.severity() && r.reaction == allergy.reaction()));
+                assert!(allergy.to_string().starts_with(&format!("{} allergy to ", allergy.severity())));
+            }
+            assert_eq!(10, roll_log::since(mark).iter().filter(|r| r.table() == "649C").count());
         }
     }
-}
\ No newline at end of file
+}
@@ -1,29 +1,148 @@
 pub mod behavior_tag {
     use std::fmt::Display;
 
+    use cchol_pm::HasRollRange;
+    use lazy_static::lazy_static;
     use serde::{Deserialize, Serialize};
     use rpgassist::ext::IsNamed;
 
-    use crate::{traits::personality::{TraitRollResult, TraitVec}};
+    use crate::{data::{blame_packs, check, load_json, loaded}, error::Result, ext::IsZero, roll_log, roll_range::*, serialize::{deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}, traits::personality::{AffectsAlignment, Alignment, PersonalityTrait, TraitRollResult, TraitVec, exotic_trait::ExoticTrait}};
 
-    #[derive(Debug, Deserialize, Serialize, Clone)]
-    pub enum BehaviorTag {
+    static BEHAVIOR_TAG_FILE: &'static str = "personality_bt.json";
+    lazy_static! {
+        static ref BEHAVIOR_TAGS_DATA: Result<Vec<BehaviorTagNote>> = load_json(BEHAVIOR_TAG_FILE);
+        static ref BEHAVIOR_TAGS: &'static Vec<BehaviorTagNote> = loaded(&BEHAVIOR_TAGS_DATA);
+
+        static ref BEHAVIOR_TAGS_RANGE: RollRange = validate_cr_ranges("BEHAVIOR_TAGS", &BEHAVIOR_TAGS, None);
+    }
+
+    /// Load and validate [BehaviorTag] data.
+    pub(crate) fn try_load() -> Result<()> {
+        let tags = check(&BEHAVIOR_TAGS_DATA)?;
+        try_validate_cr_ranges("BEHAVIOR_TAGS", tags, None).map_err(blame_packs(BEHAVIOR_TAG_FILE))?;
+        Ok(())
+    }
 
+    /// Behavior tag table entry.
+    #[derive(Debug, Deserialize, Clone, HasRollRange)]
+    struct BehaviorTagNote {
+        name: String,
+        kind: BehaviorKind,
+        #[serde(default)]
+        alignment: Alignment,
+        #[serde(deserialize_with = "deserialize_cr_range")]
+        _cr_range: RollRange,
     }
 
+    /// Kinds of [BehaviorTag].
+    #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+    pub enum BehaviorKind {
+        Habit,
+        Compulsion,
+        Mannerism,
+    }
+
+    impl Display for BehaviorKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", match self {
+                Self::Habit => "habit",
+                Self::Compulsion => "compulsion",
+                Self::Mannerism => "mannerism",
+            })
+        }
+    }
+
+    /// An odd habit, compulsion or mannerism.
+    #[derive(Debug, Deserialize, Serialize, Clone)]
+    pub struct BehaviorTag {
+        name: String,
+        kind: BehaviorKind,
+        #[serde(default)]
+        alignment: Alignment,
+    } impl BehaviorTag {
+        /// Get the [BehaviorKind].
+        pub fn kind(&self) -> BehaviorKind {
+            self.kind
+        }
+    }
+
+    /// 649D: Generate a random behavior tag — one not already in `bans`.
     pub fn random(bans: &TraitVec) -> TraitRollResult {
-        unimplemented!()
+        let mut bail_out_at_zero = 100;
+        loop {
+            bail_out_at_zero -= 1;
+
+            let (roll, note) = BEHAVIOR_TAGS.roll_in_range(&BEHAVIOR_TAGS_RANGE);
+            if !bans.iter().any(|t| t.name() == note.name) {
+                roll_log::record("649D", roll, &[], &note.name);
+                return TraitRollResult::Add(BehaviorTag {
+                    name: note.name.clone(),
+                    kind: note.kind,
+                    alignment: note.alignment,
+                }.into());
+            }
+
+            if bail_out_at_zero.is_zero() {
+                log::error!("All behavior tag options exhausted!");
+                return TraitRollResult::NoMatch;
+            }
+        }
     }
 
     impl IsNamed for BehaviorTag {
         fn name(&self) -> &str {
-            unimplemented!()
+            &self.name
+        }
+    }
+
+    impl AffectsAlignment for BehaviorTag {
+        fn alignment(&self) -> Alignment {
+            self.alignment
         }
     }
 
     impl Display for BehaviorTag {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            unimplemented!()
+            write!(f, "{} ({})", self.name.to_lowercase(), self.kind)
+        }
+    }
+
+    impl From<BehaviorTag> for PersonalityTrait {
+        fn from(value: BehaviorTag) -> Self {
+            PersonalityTrait::EX(ExoticTrait::BT(value))
+        }
+    }
+
+    #[cfg(test)]
+    mod behavior_tag_tests {
+        use crate::traits::personality::personality_tests::assert_respects_bans;
+
+        use super::*;
+
+        #[test]
+        fn behavior_tag_file_data_integrity() {
+            try_load().unwrap();
+        }
+
+        #[test]
+        fn behavior_tags_respect_bans() {
+            let everything = BEHAVIOR_TAGS.iter()
+                .map(|t| BehaviorTag { name: t.name.clone(), kind: t.kind, alignment: t.alignment }.into())
+                .collect();
+            assert_respects_bans(random, 10, Some(everything));
+        }
+
+        #[test]
+        fn kind_and_alignment_come_through() {
+            let mark = roll_log::mark();
+            for tag in assert_respects_bans(random, 10, None) {
+                let PersonalityTrait::EX(ExoticTrait::BT(tag)) = tag else { panic!("Not a behavior tag: {tag:?}") };
+                let note = BEHAVIOR_TAGS.iter().find(|t| t.name == tag.name()).unwrap();
+                assert_eq!(note.kind, tag.kind());
+                assert_eq!(note.alignment, tag.alignment());
+                assert!(tag.to_string().ends_with(&format!("({})", tag.kind())));
+            }
+            assert_eq!(10, roll_log::since(mark).iter().filter(|r| r.table() == "649D").count());
         }
     }
-}
\ No newline at end of file
+}
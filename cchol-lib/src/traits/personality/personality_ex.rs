@@ -1,11 +1,10 @@
 pub mod exotic_trait {
     use std::fmt::Display;
 
-    use dicebag::DiceExt;
     use serde::{Deserialize, Serialize};
     use rpgassist::ext::IsNamed;
 
-    use crate::{traits::personality::{TraitVec, TraitRollResult, allergies::{self, Allergy}, behavior_tag::{self, BehaviorTag}, mental_affliction::{self, MentalAffliction}, phobias::{self, Phobia}, sexual_disorder::{self, SexualDisorder}}};
+    use crate::{dice::DiceExt, traits::personality::{TraitVec, TraitRollResult, allergies::{self, Allergy}, behavior_tag::{self, BehaviorTag}, mental_affliction::{self, MentalAffliction}, phobias::{self, Phobia}, sexual_disorder::{self, SexualDisorder}}};
 
     #[derive(Debug, Deserialize, Serialize, Clone)]
     pub enum ExoticTrait {
@@ -18,7 +17,6 @@ pub mod exotic_trait {
 
     impl Display for ExoticTrait {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            unimplemented!();
             match self {
                 Self::AL(al) => write!(f, "{al}"),
                 Self::BT(bt) => write!(f, "{bt}"),
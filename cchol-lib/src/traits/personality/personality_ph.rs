@@ -1,29 +1,221 @@
 pub mod phobias {
     use std::fmt::Display;
 
+    use cchol_pm::HasRollRange;
+    use lazy_static::lazy_static;
     use serde::{Deserialize, Serialize};
     use rpgassist::ext::IsNamed;
+    use strum::IntoEnumIterator;
 
-    use crate::{traits::personality::{TraitVec, TraitRollResult}};
+    use crate::{animal::Animal, data::{blame_packs, check, load_json, loaded}, dice::RandomOf, error::{CcholError, Result}, ext::IsZero, misc::Substance, racial::{Monster, Race}, roll_log, roll_range::*, serialize::{deserialize_cr_range, try_validate_cr_ranges, validate_cr_ranges}, traits::personality::{PersonalityTrait, TraitRollResult, TraitStrength, TraitVec, exotic_trait::ExoticTrait}};
 
+    static PHOBIA_FILE: &'static str = "personality_ph.json";
+    lazy_static! {
+        static ref PHOBIA_FILE_DATA: Result<PhobiaFile> = load_json(PHOBIA_FILE);
+        static ref PHOBIA_DATA: &'static PhobiaFile = loaded(&PHOBIA_FILE_DATA);
+
+        static ref PHOBIAS_RANGE: RollRange = validate_cr_ranges("PHOBIAS", &PHOBIA_DATA.phobias, None);
+    }
+
+    /// Load and validate [Phobia] data.
+    pub(crate) fn try_load() -> Result<()> {
+        let data = check(&PHOBIA_FILE_DATA)?;
+        try_validate_cr_ranges("PHOBIAS", &data.phobias, None).map_err(blame_packs(PHOBIA_FILE))?;
+        for note in &data.phobias {
+            if note.object.is_some() == note.source.is_some() {
+                return Err(blame_packs(PHOBIA_FILE)(CcholError::Validation {
+                    table: PHOBIA_FILE.into(),
+                    reason: format!("Phobia at {:?} needs either 'object' or 'source', not both nor neither", note._cr_range)
+                }));
+            }
+        }
+        for intensity in [TraitStrength::Trivial, TraitStrength::Weak, TraitStrength::Average, TraitStrength::Strong, TraitStrength::Driving, TraitStrength::Obsessive] {
+            if !data.effects.iter().any(|e| e.intensity == intensity) {
+                return Err(blame_packs(PHOBIA_FILE)(CcholError::Validation {
+                    table: PHOBIA_FILE.into(),
+                    reason: format!("No effect for '{intensity}' phobias")
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    /// JSON deserialize struct.
+    #[derive(Debug, Deserialize)]
+    struct PhobiaFile {
+        phobias: Vec<PhobiaNote>,
+        effects: Vec<EffectNote>,
+    }
+
+    /// Where the object of fear is rolled from, if not fixed.
+    #[derive(Debug, Deserialize, Clone, Copy)]
+    enum PhobiaSource {
+        Monster,
+        Animal,
+        Race,
+        Substance,
+    }
+
+    /// Phobia table entry.
+    #[derive(Debug, Deserialize, Clone, HasRollRange)]
+    struct PhobiaNote {
+        #[serde(default)]
+        object: Option<String>,
+        #[serde(default)]
+        source: Option<PhobiaSource>,
+        #[serde(deserialize_with = "deserialize_cr_range")]
+        _cr_range: RollRange,
+    }
+
+    /// Effect table entry.
+    #[derive(Debug, Deserialize, Clone)]
+    struct EffectNote {
+        intensity: TraitStrength,
+        effect: String,
+    }
+
+    /// The object of a [Phobia].
+    #[derive(Debug, Deserialize, Serialize, Clone)]
+    pub enum PhobiaObject {
+        /// Heights, darkness, crowds, …
+        Other(String),
+        Monster(Monster),
+        Animal(Animal),
+        Race(String),
+        Substance(Substance),
+    } impl PhobiaObject {
+        /// Generate a random [PhobiaObject] from `note`.
+        fn random(note: &PhobiaNote) -> Self {
+            match (&note.object, note.source) {
+                (Some(object), _) => Self::Other(object.clone()),
+                (_, Some(PhobiaSource::Monster)) => loop {
+                    // "Giant <Animal>" and such are not much to be afraid of, as is…
+                    let monster = Monster::random();
+                    if !monster.name().contains('<') {
+                        break Self::Monster(monster)
+                    }
+                },
+                (_, Some(PhobiaSource::Animal)) => Self::Animal(Animal::random()),
+                (_, Some(PhobiaSource::Race)) => Self::Race(Race::random_nonhuman().name().into()),
+                (_, Some(PhobiaSource::Substance)) => Self::Substance(Substance::iter().collect::<Vec<_>>().random_of()),
+                // validated at try_load()
+                (None, None) => unreachable!()
+            }
+        }
+    }
+
+    impl Display for PhobiaObject {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Other(object) => write!(f, "{object}"),
+                Self::Monster(m) => write!(f, "the {}", m.name().to_lowercase()),
+                Self::Animal(Animal::Wild(a) | Animal::Pet(a)) => write!(f, "the {}", a.name().to_lowercase()),
+                Self::Race(race) => write!(f, "the {race}"),
+                Self::Substance(s) => write!(f, "{s}"),
+            }
+        }
+    }
+
+    /// A phobia — the object of fear, how intense the fear is, and what it does when faced.
     #[derive(Debug, Deserialize, Serialize, Clone)]
-    pub enum Phobia {
+    pub struct Phobia {
+        name: String,
+        object: PhobiaObject,
+        intensity: TraitStrength,
+        effect: String,
+    } impl Phobia {
+        /// Get the [object][PhobiaObject] of fear.
+        pub fn object(&self) -> &PhobiaObject {
+            &self.object
+        }
 
+        /// Get the phobia's intensity.
+        pub fn intensity(&self) -> TraitStrength {
+            self.intensity
+        }
+
+        /// What facing the object of fear does.
+        pub fn effect(&self) -> &str {
+            &self.effect
+        }
     }
 
+    /// 649B: Generate a random phobia — of something not already in `bans`.
     pub fn random(bans: &TraitVec) -> TraitRollResult {
-        unimplemented!()
+        let mut bail_out_at_zero = 100;
+        loop {
+            bail_out_at_zero -= 1;
+
+            let (roll, note) = PHOBIA_DATA.phobias.roll_in_range(&PHOBIAS_RANGE);
+            let object = PhobiaObject::random(note);
+            let name = format!("Fear of {object}");
+            if !bans.iter().any(|t| t.name() == name) {
+                roll_log::record("649B", roll, &[], &name);
+                let intensity = TraitStrength::random();
+                let effects: Vec<&EffectNote> = PHOBIA_DATA.effects.iter()
+                    .filter(|e| e.intensity == intensity)
+                    .collect();
+                return TraitRollResult::Add(Phobia {
+                    name,
+                    object,
+                    intensity,
+                    effect: effects.random_of().effect.clone(),
+                }.into());
+            }
+
+            if bail_out_at_zero.is_zero() {
+                log::error!("All phobia options exhausted!");
+                return TraitRollResult::NoMatch;
+            }
+        }
     }
 
     impl IsNamed for Phobia {
         fn name(&self) -> &str {
-            unimplemented!()
+            &self.name
         }
     }
 
     impl Display for Phobia {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            unimplemented!()
+            write!(f, "{} fear of {} ({})", self.intensity, self.object, self.effect)
+        }
+    }
+
+    impl From<Phobia> for PersonalityTrait {
+        fn from(value: Phobia) -> Self {
+            PersonalityTrait::EX(ExoticTrait::PH(value))
+        }
+    }
+
+    #[cfg(test)]
+    mod phobia_tests {
+        use crate::traits::personality::personality_tests::assert_respects_bans;
+
+        use super::*;
+
+        #[test]
+        fn phobia_file_data_integrity() {
+            try_load().unwrap();
+        }
+
+        #[test]
+        fn phobias_respect_bans() {
+            // the objects of fear are too many to ban them all.
+            assert_respects_bans(random, 100, None);
+        }
+
+        #[test]
+        fn effect_fits_intensity() {
+            let mark = roll_log::mark();
+            let phobias = assert_respects_bans(random, 10, None);
+            for phobia in &phobias {
+                let PersonalityTrait::EX(ExoticTrait::PH(phobia)) = phobia else { panic!("Not a phobia: {phobia:?}") };
+                assert!(PHOBIA_DATA.effects.iter().any(|e| e.intensity == phobia.intensity() && e.effect == phobia.effect()));
+                assert_eq!(format!("Fear of {}", phobia.object()), phobia.name());
+                assert!(phobia.to_string().starts_with(&format!("{} fear of ", phobia.intensity())));
+            }
+            assert_eq!(phobias.len(), roll_log::since(mark).iter().filter(|r| r.table() == "649B").count());
         }
     }
-}
\ No newline at end of file
+}
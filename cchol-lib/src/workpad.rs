@@ -4,9 +4,9 @@
 
 use std::{cell::Cell, marker::PhantomData, ops::AddAssign};
 
-use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}, stat::Stat};
+use rpgassist::{ext::IsNamed, gender::{Gender, HasGender}, ranking::Rank, stat::Stat};
 
-use crate::{StatMap, modifier::{BiMod, CuMod, LegitMod, SolMod, TiMod}, racial::Race, social::{birth::Birth, culture::{Culture, CultureCoreType}, status::SocialStatus}, traits::{HasCulture, HasCultureCoreType}};
+use crate::{StatMap, dice, error::{CcholError, Result}, events::GiftOrLegacy, misc::Occupations, pc::PlayerKnown, roll_log::{self, RollLog}, modifier::{BiMod, CuMod, LegitMod, SolMod, TiMod}, places::birthplace::PlaceOfBirth, racial::Race, skill::{Skill, SkillBase}, social::{birth::{Birth, Parentage}, birth_legitimacy::IllegitimacyReason, culture::{Culture, CultureCoreType}, status::SocialStatus}, traits::{HasCulture, HasCultureCoreType}};
 
 /// All values in the Workpad are Option<> and accessors will panic if/when
 /// accessing something out of preordained sequence.
@@ -14,29 +14,57 @@ use crate::{StatMap, modifier::{BiMod, CuMod, LegitMod, SolMod, TiMod}, racial::
 /// To ensure that things happen in correct sequence, Workpad is intentionally
 /// enforced to be single-thread-only. Don't even try any multithread
 /// shenanigans with it, the compiler'll swear at you…
+#[derive(Clone)]
 pub struct Workpad {
     _enforced_single_threading: PhantomData<*mut ()>,
+    seed: u64,
     name: Option<String>,
     gender: Option<Gender>,
     statmap: StatMap,
+    skills: Vec<Skill>,
+    possessions: Vec<GiftOrLegacy>,
     race: Option<&'static Race>,
     culture: Option<&'static Culture>,
     status: Option<SocialStatus>,
     birth: Option<Birth>,
+    occupations: Option<Occupations>,
+    // Bits of Birth that are needed while Birth itself is still underway.
+    illegitimacy_info: Option<Option<(i32, IllegitimacyReason)>>,
+    parentage: Option<Parentage>,
+    place_of_birth: Option<PlaceOfBirth>,
+    // What the player gets to know — see begin_gm_secrets().
+    player_known: Option<PlayerKnown>,
+    gm_secrets_underway: bool,
     // BiMod has to invisibly deal with its one-shot temporary boost, if any.
     // And thus - we use Cell for temp_bimod.
     temp_bimod: Cell<i32>,
 } impl Workpad {
     pub fn new() -> Self {
+        Self::with_seed(dice::random_seed())
+    }
+
+    /// New Workpad with the dice (re)seeded with `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        dice::reseed(seed);
+        roll_log::clear();
         Self {
             _enforced_single_threading: PhantomData,
+            seed,
             name: None,
             gender: None,
             statmap: StatMap::default(),
+            skills: vec![],
+            possessions: vec![],
             race: None,
             culture: None,
             status: None,
             birth: None,
+            occupations: None,
+            illegitimacy_info: None,
+            parentage: None,
+            place_of_birth: None,
+            player_known: None,
+            gm_secrets_underway: false,
             // temporaries
             temp_bimod: 0.into(),
         }
@@ -50,10 +78,72 @@ pub struct Workpad {
         self.name = Some(name.into())
     }
 
+    /// Set (il)legitimacy info — needed by e.g. [PlaceOfBirth] before [Birth] is finalized.
+    pub fn set_illegitimacy_info(&mut self, info: Option<(i32, IllegitimacyReason)>) {
+        self.illegitimacy_info = Some(info)
+    }
+
+    /// Get [Parentage] for e.g. tragedies to reshape before [Birth] is finalized, if determined already.
+    pub(crate) fn parentage_mut(&mut self) -> Option<&mut Parentage> {
+        self.parentage.as_mut()
+    }
+
+    /// Take the [Parentage] to finalize [Birth] with.
+    pub(crate) fn take_parentage(&mut self) -> Parentage {
+        self.parentage.take()
+            .expect("`Parentage` not yet determined!")
+    }
+
+    /// Start rolling up GM-only stuff.
+    ///
+    /// Whatever those do to stats, skills, possessions, social status or family
+    /// stays off of what the player gets to know; anything after [end_gm_secrets][Workpad::end_gm_secrets]
+    /// goes to both.
+    pub(crate) fn begin_gm_secrets(&mut self) {
+        if self.player_known.is_none() {
+            let parentage = self.parentage.as_ref()
+                .expect("`Parentage` not yet determined!");
+            self.player_known = Some(PlayerKnown {
+                stats: self.statmap.clone(),
+                skills: self.skills.clone(),
+                possessions: self.possessions.clone(),
+                status: self.get_social_status().clone(),
+                family: parentage.family().clone(),
+                siblings: parentage.siblings().to_vec(),
+            });
+        }
+        self.gm_secrets_underway = true;
+    }
+
+    /// Done rolling up GM-only stuff.
+    pub(crate) fn end_gm_secrets(&mut self) {
+        self.gm_secrets_underway = false;
+    }
+
+    /// Get what the player gets to know, if it differs from the whole truth.
+    pub(crate) fn player_known(&self) -> Option<&PlayerKnown> {
+        self.player_known.as_ref()
+    }
+
+    /// Get what the player gets to know, for changes made in the open.
+    fn player_known_mut(&mut self) -> Option<&mut PlayerKnown> {
+        if self.gm_secrets_underway {None} else {self.player_known.as_mut()}
+    }
+
     //---------------------------------
     //
     // A bunch of getters …
     //
+    /// Get the dice seed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Get the [roll log][RollLog] so far.
+    pub fn roll_log(&self) -> RollLog {
+        roll_log::snapshot()
+    }
+
     /// Get [Race]
     pub fn race(&self) -> &'static Race {
         self.race.as_ref()
@@ -71,12 +161,39 @@ pub struct Workpad {
         &self.statmap
     }
 
+    /// Get [skills][Skill] gained so far.
+    pub fn get_skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    /// Get possessions gained so far, e.g. [gifts and legacies][GiftOrLegacy].
+    pub fn get_possessions(&self) -> &[GiftOrLegacy] {
+        &self.possessions
+    }
+
     /// Get [Birth]
     pub fn get_birth(&self) -> &Birth {
         self.birth.as_ref()
             .expect("`Birth` hasn't happened yet!")
     }
 
+    /// Get [Occupations]
+    pub fn get_occupations(&self) -> &Occupations {
+        self.occupations.as_ref()
+            .expect("`Occupations` not yet determined!")
+    }
+
+    //---------------------------------
+    //
+    // …and their non-panicky counterparts, where there's a need for one —
+    // Workpad stays crate-internal; library users see out-of-sequence errors
+    // through e.g. [Stepwise][crate::stepwise::Stepwise].
+    //
+    /// Get [SocialStatus], if determined already.
+    pub fn try_social_status(&self) -> Result<&SocialStatus> {
+        self.status.as_ref().ok_or(CcholError::OutOfSequence { what: "SocialStatus" })
+    }
+
     //---------------------------------
     //
     // "Boosters"
@@ -88,6 +205,11 @@ pub struct Workpad {
         self.temp_bimod = by.into();
         self
     }
+
+    /// Peek at the pending one-shot **BiMod** boost, if any, without using it up.
+    pub fn bimod_boost(&self) -> i32 {
+        self.temp_bimod.get()
+    }
 }
 
 impl HasGender for Workpad {
@@ -102,6 +224,7 @@ impl BiMod for Workpad {
     fn bimod(&self) -> i32 {
         let base_bimod = self.birth.as_ref()
             .and_then(|b| Some(b.bimod()))
+            .or_else(|| self.place_of_birth.as_ref().and_then(|p| Some(p.bimod())))
             .expect("`Birth` not yet happened!");
         
         // one-shot temporary booster, used e.g. for UBO rerolls for higher rerolled value…
@@ -124,6 +247,7 @@ impl LegitMod for Workpad {
     fn legitmod(&self) -> i32 {
         self.birth.as_ref()
             .and_then(|b| Some(b.legitmod()))
+            .or_else(|| self.illegitimacy_info.as_ref().and_then(|i| Some(i.legitmod())))
             .expect("`Birth` not yet happened!")
     }
 } impl LegitMod for &mut Workpad {/*delegate*/fn legitmod(&self) -> i32 {(**self).legitmod()}}
@@ -150,10 +274,44 @@ impl TiMod for Workpad {
 /// Workpad += Stat
 impl AddAssign<Stat> for Workpad {
     fn add_assign(&mut self, rhs: Stat) {
+        if let Some(known) = self.player_known_mut() {
+            known.stats += rhs.clone();
+        }
         self.statmap += rhs
     }
 } impl AddAssign<Stat> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: Stat) {**self += rhs}}
 
+/// Gain a skill in `skills`; ranks of an already known skill add up.
+fn gain_skill(skills: &mut Vec<Skill>, base: &SkillBase, rank: i32) {
+    match skills.iter_mut().find(|s| s.name() == base.name()) {
+        Some(skill) => *skill += rank,
+        None => skills.push(Skill::from((base.clone(), Rank::from(rank))))
+    }
+}
+
+//-------------------------------------
+/// Workpad += (SkillBase, rank)
+impl AddAssign<(&SkillBase, i32)> for Workpad {
+    /// Gain a skill; ranks of an already known skill add up.
+    fn add_assign(&mut self, (base, rank): (&SkillBase, i32)) {
+        if let Some(known) = self.player_known_mut() {
+            gain_skill(&mut known.skills, base, rank);
+        }
+        gain_skill(&mut self.skills, base, rank);
+    }
+} impl AddAssign<(&SkillBase, i32)> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: (&SkillBase, i32)) {**self += rhs}}
+
+//-------------------------------------
+/// Workpad += GiftOrLegacy
+impl AddAssign<GiftOrLegacy> for Workpad {
+    fn add_assign(&mut self, rhs: GiftOrLegacy) {
+        if let Some(known) = self.player_known_mut() {
+            known.possessions.push(rhs.clone());
+        }
+        self.possessions.push(rhs)
+    }
+} impl AddAssign<GiftOrLegacy> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: GiftOrLegacy) {**self += rhs}}
+
 //-------------------------------------
 /// Workpad += Gender
 impl AddAssign<Gender> for Workpad {
@@ -182,6 +340,9 @@ impl AddAssign<&'static Race> for Workpad {
 /// Workpad += SocialStatus
 impl AddAssign<SocialStatus> for Workpad {
     fn add_assign(&mut self, rhs: SocialStatus) {
+        if let Some(known) = self.player_known_mut() {
+            known.status = rhs.clone();
+        }
         self.status = Some(rhs)
     }
 } impl AddAssign<SocialStatus> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: SocialStatus) {**self += rhs}}
@@ -194,6 +355,30 @@ impl AddAssign<Birth> for Workpad {
     }
 } impl AddAssign<Birth> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: Birth) {**self += rhs}}
 
+//-------------------------------------
+/// Workpad += Occupations
+impl AddAssign<Occupations> for Workpad {
+    fn add_assign(&mut self, rhs: Occupations) {
+        self.occupations = Some(rhs)
+    }
+} impl AddAssign<Occupations> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: Occupations) {**self += rhs}}
+
+//-------------------------------------
+/// Workpad += Parentage
+impl AddAssign<Parentage> for Workpad {
+    fn add_assign(&mut self, rhs: Parentage) {
+        self.parentage = Some(rhs)
+    }
+} impl AddAssign<Parentage> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: Parentage) {**self += rhs}}
+
+//-------------------------------------
+/// Workpad += PlaceOfBirth
+impl AddAssign<PlaceOfBirth> for Workpad {
+    fn add_assign(&mut self, rhs: PlaceOfBirth) {
+        self.place_of_birth = Some(rhs)
+    }
+} impl AddAssign<PlaceOfBirth> for &mut Workpad {/*delegate*/fn add_assign(&mut self, rhs: PlaceOfBirth) {**self += rhs}}
+
 impl IsNamed for Workpad {
     fn name(&self) -> &str {
         self.name.as_ref()
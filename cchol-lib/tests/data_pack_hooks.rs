@@ -0,0 +1,16 @@
+use std::fs;
+
+use cchol_lib::{CcholError, data::{load_data, set_data_packs}};
+
+#[test]
+fn pack_with_unknown_name_hook_fails_to_load() {
+    let pack = std::env::temp_dir().join(format!("cchol-hook-pack-{}", std::process::id())).join("homebrew");
+    fs::create_dir_all(&pack).unwrap();
+    fs::write(pack.join("curse.json"), r#"{
+        "casters": ["a <Bogus> of ill repute"]
+    }"#).unwrap();
+
+    set_data_packs(vec![pack]).unwrap();
+    let Err(CcholError::Validation { reason, .. }) = load_data() else { panic!("Unknown name hook slipped through!") };
+    assert!(reason.contains("'<Bogus>'") && reason.contains("data packs applied to 'curse.json'"), "{reason}");
+}
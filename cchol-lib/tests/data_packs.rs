@@ -0,0 +1,24 @@
+use std::fs;
+
+use cchol_lib::{data::{load_data, set_data_packs}, racial::Race, roll_range::UseRollRange};
+use rpgassist::ext::IsNamed;
+
+#[test]
+fn pack_adds_replaces_and_removes() {
+    let pack = std::env::temp_dir().join(format!("cchol-pack-{}", std::process::id())).join("homebrew");
+    fs::create_dir_all(&pack).unwrap();
+    fs::write(pack.join("race.json"), r#"[
+        // elf gives up some room for kobolds…
+        { "name": "elf", "_cr_range": [3361,3700], "racial_events": "Elf" },
+        { "name": "kobold", "_cr_range": [3701,3840] }
+    ]"#).unwrap();
+    fs::write(pack.join("deity.json"), r#"[
+        { "name": "Beast Gods", "_remove": true },
+        { "name": "Totem Spirits", "_cr_range": 2, "gender": "NeverApplicable" }
+    ]"#).unwrap();
+
+    set_data_packs(vec![pack]).unwrap();
+    assert_eq!(Ok(()), load_data());
+    assert_eq!("kobold", Race::try_from_name("kobold").unwrap().name());
+    assert_eq!(3361..=3700, *Race::try_from_name("elf").unwrap().roll_range());
+}
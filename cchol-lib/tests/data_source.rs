@@ -0,0 +1,13 @@
+use cchol_lib::{CcholError, data::{DataSource, data_source, load_data, set_data_source}};
+
+#[test]
+fn data_source_is_selected_once() {
+    let bogus = DataSource::Dir("/no/such/place".into());
+    assert!(matches!(set_data_source(bogus), Err(CcholError::DataFile { .. })));
+
+    let dir = DataSource::Dir(concat!(env!("CARGO_MANIFEST_DIR"), "/data").into());
+    assert_eq!(Ok(()), set_data_source(dir.clone()));
+    assert_eq!(&dir, data_source());
+    assert_eq!(Ok(()), load_data());
+    assert_eq!(Err(CcholError::DataSourceLocked), set_data_source(DataSource::Embedded));
+}
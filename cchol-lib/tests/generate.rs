@@ -0,0 +1,48 @@
+use cchol_lib::{CcholError, GenerationOptions, data::load_data, generate_player_background, try_generate_player_background};
+
+#[test]
+fn same_seed_same_character() {
+    let _ = env_logger::try_init();
+    let mut options = GenerationOptions::new("Tester");
+    options.with_seed(42);
+    let pc = generate_player_background(&options);
+    assert_eq!(Some(42), pc.seed());
+    let a = serde_jsonc::to_string(&pc).unwrap();
+    let b = serde_jsonc::to_string(&generate_player_background(&options)).unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn roll_log_is_kept_on_request() {
+    let _ = env_logger::try_init();
+    let mut options = GenerationOptions::new("Tester");
+    options.with_seed(42);
+    assert!(generate_player_background(&options).roll_log().is_empty());
+    options.with_roll_log();
+    let pc = generate_player_background(&options);
+    let tables: Vec<&str> = pc.roll_log().iter().map(|r| r.table()).collect();
+    assert!(tables.contains(&"101"));
+    assert!(tables.contains(&"106"));
+    assert!(tables.contains(&"110"));
+}
+
+#[test]
+fn data_loads_cleanly() {
+    assert_eq!(Ok(()), load_data());
+}
+
+#[test]
+fn unknown_names_are_errors() {
+    let mut options = GenerationOptions::new("Tester");
+    options.with_race("Hobgoblinoid");
+    assert_eq!(
+        CcholError::UnknownName { kind: "race", name: "Hobgoblinoid".into() },
+        try_generate_player_background(&options).unwrap_err()
+    );
+    let mut options = GenerationOptions::new("Tester");
+    options.with_culture("Spacefaring");
+    assert!(matches!(try_generate_player_background(&options), Err(CcholError::UnknownName { kind: "culture", .. })));
+    let mut options = GenerationOptions::new("Tester");
+    options.with_gender("Toaster");
+    assert!(matches!(try_generate_player_background(&options), Err(CcholError::UnknownName { kind: "gender", .. })));
+}
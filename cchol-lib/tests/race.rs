@@ -11,8 +11,8 @@ mod race_tests {
     #[test]
     fn shift_nomad_down() {
         let _ = env_logger::try_init();
-        let r = Race::from("reptileman");
-        let c = Culture::from("nomad");
+        let r = Race::from(Some("reptileman"));
+        let c = Culture::from(Some("nomad"));
         let c = r.shift_culture_if_needed(&c);
         assert!(!c.is_nomad());
     }
@@ -20,8 +20,8 @@ mod race_tests {
     #[test]
     fn shift_civilized_up() {
         let _ = env_logger::try_init();
-        let r = Race::from("reptileman");
-        let c = Culture::from("civilized");
+        let r = Race::from(Some("reptileman"));
+        let c = Culture::from(Some("civilized"));
         let c = r.shift_culture_if_needed(c);
         assert!(!c.is_civilized());
     }
@@ -29,8 +29,8 @@ mod race_tests {
     #[test]
     fn culture_cap() {
         let _ = env_logger::try_init();
-        let r = Race::from("dwarf");
-        let c = Culture::from("decadent");
+        let r = Race::from(Some("dwarf"));
+        let c = Culture::from(Some("decadent"));
         // this should peg the Decadent culture down a step
         let c = r.shift_culture_if_needed(c);
         assert!(c.is_civilized())
@@ -43,7 +43,7 @@ mod race_tests {
 
         #[test]
         fn human_has_no_special_events() {
-            let r = Race::from("human");
+            let r = Race::from(Some("human"));
             let e = r.has_racial_events(true);
             assert!(e.is_none());
         }
@@ -56,7 +56,7 @@ mod race_tests {
                 ("halfling", RacialEvent::Halfling),
                 ("orc", RacialEvent::Monster)];
             nhs.iter().for_each(|(r, e)| {
-                let r = Race::from(r);
+                let r = Race::from(Some(r));
                 let evt = r.has_racial_events(false);
                 let Some(evt) = evt else {
                     panic!("No racial event for '{}'?!", r.name())